@@ -0,0 +1,196 @@
+//! Batch mode: one expression per line, or one per row of a CSV column.
+
+use anyhow::{Context, anyhow};
+use std::io::{BufRead, Write};
+
+/// Options of a batch run.
+#[derive(Debug, Clone, Default)]
+pub struct BatchOptions {
+    /// Keeps going after an expression fails to parse, leaving its result empty.
+    pub continue_on_error: bool,
+    /// Reads the input as CSV instead of one expression per line.
+    pub csv: Option<CsvOptions>,
+}
+
+/// How expressions are read from CSV input.
+#[derive(Debug, Clone, Default)]
+pub struct CsvOptions {
+    /// Name or 1-based index of the column holding the expressions. Defaults to the
+    /// first column.
+    pub column: Option<String>,
+    /// Whether the first row is a header. Columns can only be selected by name if it is.
+    pub has_header: bool,
+    /// Writes the original columns followed by the result instead of the result alone.
+    pub passthrough: bool,
+}
+
+/// An expression that failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    /// 1-based line number, or row number for CSV input, including the header.
+    pub line: usize,
+    /// The expression.
+    pub input: String,
+    /// The error, as rendered for stderr.
+    pub message: String,
+}
+
+/// What happened during a batch run.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    /// Number of expressions read, not counting blank lines.
+    pub total: usize,
+    /// Expressions that failed to parse.
+    pub failures: Vec<Failure>,
+    /// Whether the run stopped at the first failure, leaving lines unread.
+    pub stopped: bool,
+}
+
+impl Summary {
+    /// Writes the number of failures and the failed lines.
+    pub fn report(&self, mut errors: impl Write) -> std::io::Result<()> {
+        if self.failures.is_empty() {
+            return Ok(());
+        }
+        writeln!(
+            errors,
+            "{} of {} expressions failed:",
+            self.failures.len(),
+            self.total
+        )?;
+        for failure in &self.failures {
+            writeln!(errors, "  line {}: {:?}", failure.line, failure.input)?;
+        }
+        if self.stopped {
+            writeln!(
+                errors,
+                "Stopped at the first failure; use --continue-on-error to parse the rest"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Resolves every expression of `reader` with `resolve`, writing one result per line to
+/// `writer` and errors to `errors`.
+///
+/// `resolve` returns the formatted result or the formatted error. Unless
+/// `options.continue_on_error` is set, the run stops at the first error.
+pub fn run(
+    reader: impl BufRead,
+    mut writer: impl Write,
+    mut errors: impl Write,
+    options: &BatchOptions,
+    resolve: impl Fn(&str) -> Result<String, String>,
+) -> anyhow::Result<Summary> {
+    if let Some(csv_options) = &options.csv {
+        return run_csv(reader, writer, errors, options, csv_options, resolve);
+    }
+
+    let mut summary = Summary::default();
+    for (index, line) in reader.lines().enumerate() {
+        let line = line.context("Failed to read input")?;
+        let input = line.trim();
+        if input.is_empty() {
+            writeln!(writer)?;
+            continue;
+        }
+
+        summary.total += 1;
+        match resolve(input) {
+            Ok(result) => writeln!(writer, "{}", result)?,
+            Err(message) => {
+                writeln!(errors, "{}", message)?;
+                summary.failures.push(Failure {
+                    line: index + 1,
+                    input: input.to_string(),
+                    message,
+                });
+                if !options.continue_on_error {
+                    summary.stopped = true;
+                    break;
+                }
+                writeln!(writer)?;
+            }
+        }
+    }
+
+    Ok(summary)
+}
+
+fn run_csv(
+    reader: impl BufRead,
+    mut writer: impl Write,
+    mut errors: impl Write,
+    options: &BatchOptions,
+    csv_options: &CsvOptions,
+    resolve: impl Fn(&str) -> Result<String, String>,
+) -> anyhow::Result<Summary> {
+    let mut summary = Summary::default();
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(csv_options.has_header)
+        .flexible(true)
+        .from_reader(reader);
+
+    let column = match (&csv_options.column, csv_options.has_header) {
+        (None, _) => 0,
+        (Some(column), has_header) => match column.parse::<usize>() {
+            Ok(0) => return Err(anyhow!("Column indexes start at 1")),
+            Ok(index) => index - 1,
+            Err(_) if has_header => csv_reader
+                .headers()?
+                .iter()
+                .position(|header| header.trim() == column.trim())
+                .ok_or_else(|| anyhow!("No column named {:?}", column))?,
+            Err(_) => return Err(anyhow!("Columns can only be named when there is a header")),
+        },
+    };
+
+    if csv_options.has_header && csv_options.passthrough {
+        let mut headers = csv_reader.headers()?.clone();
+        headers.push_field("parsed");
+        write_record(&mut writer, &headers)?;
+    }
+
+    let first_line = if csv_options.has_header { 2 } else { 1 };
+    for (index, record) in csv_reader.records().enumerate() {
+        let record = record.context("Failed to read CSV input")?;
+        let input = record.get(column).unwrap_or_default().trim().to_string();
+
+        summary.total += 1;
+        let result = match resolve(&input) {
+            Ok(result) => result,
+            Err(message) => {
+                writeln!(errors, "{}", message)?;
+                summary.failures.push(Failure {
+                    line: index + first_line,
+                    input,
+                    message,
+                });
+                if !options.continue_on_error {
+                    summary.stopped = true;
+                    break;
+                }
+                String::new()
+            }
+        };
+
+        if csv_options.passthrough {
+            let mut record = record.clone();
+            record.push_field(&result);
+            write_record(&mut writer, &record)?;
+        } else {
+            writeln!(writer, "{}", result)?;
+        }
+    }
+
+    Ok(summary)
+}
+
+/// Writes one CSV row, quoting fields as needed.
+fn write_record(writer: &mut impl Write, record: &csv::StringRecord) -> anyhow::Result<()> {
+    let mut csv_writer = csv::WriterBuilder::new().from_writer(Vec::new());
+    csv_writer.write_record(record)?;
+    writer.write_all(&csv_writer.into_inner()?)?;
+    Ok(())
+}
@@ -0,0 +1,428 @@
+//! The commands of the `natural-date-parser` binary and their arguments.
+
+use crate::cli::batch::{self, BatchOptions, CsvOptions};
+use crate::cli::context;
+use crate::cli::error::{Error, Result};
+use crate::cli::explain;
+use crate::cli::humanize;
+use crate::cli::output::{self, DurationFormat, Format};
+use anyhow::{Context, anyhow};
+use chrono::{DateTime, FixedOffset};
+use clap::{Args, ValueEnum};
+use natural_date_parser::{ParseDateError, ParsedDate, ParserConfig, date_parser};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::path::PathBuf;
+
+/// An expression written as one or more words, so that it needs no quotes.
+#[derive(Args)]
+pub struct ExpressionArgs {
+    /// The expression. Quotes are optional.
+    #[arg(required = true, num_args = 1.., value_name = "EXPRESSION")]
+    pub words: Vec<String>,
+}
+
+impl ExpressionArgs {
+    /// The expression, with its words joined by spaces.
+    pub fn text(&self) -> String {
+        self.words.join(" ")
+    }
+}
+
+/// How instants are printed.
+#[derive(Args)]
+pub struct OutputArgs {
+    /// How the result is printed.
+    #[arg(long, value_enum, default_value_t = Format::Rfc3339)]
+    pub format: Format,
+
+    /// strftime-style pattern used with `--format custom`, for example "%Y-%m-%d".
+    #[arg(long, required_if_eq("format", "custom"))]
+    pub pattern: Option<String>,
+
+    /// Prints results with a strftime-style pattern such as "%Y-%m-%d %H:%M", or with
+    /// one of the presets date, time, iso, rfc2822 and epoch-ms, instead of `--format`.
+    #[arg(long, short, value_name = "PATTERN|PRESET", conflicts_with_all = ["format", "pattern"])]
+    pub output: Option<String>,
+}
+
+impl OutputArgs {
+    /// The format and pattern results are printed with, from `--output` or from
+    /// `--format` and `--pattern`.
+    pub fn resolve(&self) -> Result<(Format, Option<String>)> {
+        let (format, pattern) = match &self.output {
+            Some(output) => output::output_format(output).map_err(Error::Usage)?,
+            None => (self.format, self.pattern.clone()),
+        };
+        output::check_pattern(format, pattern.as_deref()).map_err(Error::Usage)?;
+        Ok((format, pattern))
+    }
+}
+
+/// Arguments of the `parse` command, which also runs when no command is given.
+#[derive(Args)]
+pub struct ParseArgs {
+    /// The expression to parse, for example: tomorrow at 3pm. Quotes are optional.
+    #[arg(value_name = "EXPRESSION", conflicts_with_all = ["batch", "input"])]
+    pub words: Vec<String>,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+
+    /// Reads expressions from stdin, one per line, and prints one result per line.
+    #[arg(long)]
+    pub batch: bool,
+
+    /// Reads expressions from a file, one per line, and prints one result per line.
+    #[arg(long, value_name = "FILE")]
+    pub input: Option<PathBuf>,
+
+    /// In batch mode, keeps going after an expression fails and leaves its result empty.
+    #[arg(long)]
+    pub continue_on_error: bool,
+
+    /// In batch mode, reads the input as CSV.
+    #[arg(long)]
+    pub csv: bool,
+
+    /// Name or 1-based index of the CSV column holding the expressions [default: 1].
+    #[arg(long, requires = "csv")]
+    pub column: Option<String>,
+
+    /// The CSV input has no header row.
+    #[arg(long, requires = "csv")]
+    pub no_header: bool,
+
+    /// Writes the original CSV columns followed by a "parsed" column with the result.
+    #[arg(long, requires = "csv")]
+    pub passthrough: bool,
+}
+
+/// Arguments of the `range` command.
+#[derive(Args)]
+pub struct RangeArgs {
+    #[command(flatten)]
+    pub expression: ExpressionArgs,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+/// Arguments of the `duration` command.
+#[derive(Args)]
+pub struct DurationArgs {
+    #[command(flatten)]
+    pub expression: ExpressionArgs,
+
+    /// How the duration is printed.
+    #[arg(long, value_enum, default_value_t = DurationFormat::Iso)]
+    pub format: DurationFormat,
+}
+
+/// Arguments of the `diff` command.
+#[derive(Args)]
+pub struct DiffArgs {
+    /// The expression the difference is counted from and the one it is counted to, such
+    /// as "today end of quarter". Separate them with "to" or "and" where the split is not
+    /// obvious, as in "today to in 2 days and 3 hours".
+    #[arg(required = true, num_args = 2.., value_name = "EXPRESSION")]
+    pub words: Vec<String>,
+
+    /// The unit the difference is printed in.
+    #[arg(long, short, value_enum, default_value_t = DiffUnit::Days)]
+    pub unit: DiffUnit,
+}
+
+/// Units the `diff` command prints differences in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DiffUnit {
+    /// Whole days, rounded toward zero.
+    Days,
+    /// Business days after the first date up to and including the second, skipping
+    /// weekends and holidays.
+    BusinessDays,
+    /// Whole hours, rounded toward zero.
+    Hours,
+    /// Whole weeks, rounded toward zero.
+    Weeks,
+    /// A description such as "3 days" or "2 months", in the nearest single unit.
+    Human,
+}
+
+/// Arguments of the `occurrences` command.
+#[derive(Args)]
+pub struct OccurrencesArgs {
+    #[command(flatten)]
+    pub expression: ExpressionArgs,
+
+    /// How many occurrences are listed [default: 5, or all of them with --between].
+    #[arg(long)]
+    pub count: Option<u32>,
+
+    /// Lists occurrences after this time instead of after now: an RFC 3339 timestamp, a
+    /// date such as "2026-01-01" or an expression such as "next month".
+    #[arg(long, value_name = "TIME", conflicts_with = "between")]
+    pub after: Option<String>,
+
+    /// Lists the occurrences from START, inclusive, until END, exclusive. Both take the
+    /// same forms as --after.
+    #[arg(long, num_args = 2, value_names = ["START", "END"])]
+    pub between: Option<Vec<String>>,
+
+    #[command(flatten)]
+    pub output: OutputArgs,
+}
+
+/// Prints the date and time an expression resolves to, or one result per line in batch
+/// mode.
+pub fn parse(args: &ParseArgs, config: &ParserConfig) -> Result<()> {
+    let (format, pattern) = args.output.resolve()?;
+    let pattern = pattern.as_deref();
+
+    if args.batch || args.input.is_some() {
+        let reader: Box<dyn BufRead> = match &args.input {
+            Some(path) => Box::new(BufReader::new(
+                File::open(path).with_context(|| format!("Failed to open {}", path.display()))?,
+            )),
+            None => Box::new(io::stdin().lock()),
+        };
+        let options = BatchOptions {
+            continue_on_error: args.continue_on_error,
+            csv: args.csv.then(|| CsvOptions {
+                column: args.column.clone(),
+                has_header: !args.no_header,
+                passthrough: args.passthrough,
+            }),
+        };
+
+        let summary = batch::run(
+            reader,
+            io::stdout().lock(),
+            io::stderr(),
+            &options,
+            |input| {
+                let parsed = date_parser::parse_with_config(input, config)
+                    .map_err(|e| output::render_error(input, &e, format))?;
+                let other_readings = other_readings(input, &parsed, format, config);
+                output::render(input, &parsed, format, pattern, &other_readings)
+                    .map_err(|e| e.to_string())
+            },
+        )?;
+        summary.report(io::stderr())?;
+        if !summary.failures.is_empty() {
+            return Err(Error::Parse);
+        }
+        return Ok(());
+    }
+
+    if args.words.is_empty() {
+        return Err(Error::Usage(anyhow!(
+            "An expression, --batch or --input is required; see --help"
+        )));
+    }
+
+    let input = args.words.join(" ");
+    let parsed = date_parser::parse_with_config(&input, config)
+        .map_err(|e| parse_failure(&input, &e, format))?;
+    let other_readings = other_readings(&input, &parsed, format, config);
+    println!(
+        "{}",
+        output::render(&input, &parsed, format, pattern, &other_readings)?
+    );
+    Ok(())
+}
+
+/// The other readings of `input` to warn about, which only JSON output lists.
+fn other_readings(
+    input: &str,
+    parsed: &ParsedDate,
+    format: Format,
+    config: &ParserConfig,
+) -> Vec<DateTime<FixedOffset>> {
+    match format {
+        Format::Json => output::other_readings(input, parsed, config),
+        _ => Vec::new(),
+    }
+}
+
+/// Prints the range a period covers.
+pub fn range(args: &RangeArgs, config: &ParserConfig) -> Result<()> {
+    let (format, pattern) = args.output.resolve()?;
+    let pattern = pattern.as_deref();
+
+    let input = args.expression.text();
+    let range = date_parser::range_from_string_with_config(&input, config)
+        .map_err(|e| parse_failure(&input, &e, format))?;
+    println!(
+        "{}",
+        output::render_range(&input, &range, format, pattern, config)?
+    );
+    Ok(())
+}
+
+/// Prints a duration, counted from the reference time.
+pub fn duration(args: &DurationArgs, config: &ParserConfig) -> Result<()> {
+    let format = match args.format {
+        DurationFormat::Json => Format::Json,
+        _ => Format::Rfc3339,
+    };
+
+    let input = args.expression.text();
+    let start = config.reference_time();
+    let text = date_parser::duration_from_string_with_config(&input, config)
+        .and_then(|offsets| {
+            let end = date_parser::apply_offsets(start, &offsets, config)?.datetime;
+            output::render_duration(&input, &offsets, start, end, args.format, config)
+        })
+        .map_err(|e| parse_failure(&input, &e, format))?;
+    println!("{}", text);
+    Ok(())
+}
+
+/// Prints when an expression is relative to the reference time, such as "in 3 days".
+pub fn humanize(args: &ExpressionArgs, config: &ParserConfig) -> Result<()> {
+    let input = args.text();
+    let parsed = date_parser::parse_with_config(&input, config)
+        .map_err(|e| parse_failure(&input, &e, Format::Rfc3339))?;
+
+    let delta = parsed
+        .datetime
+        .signed_duration_since(config.reference_time());
+    println!("{}", humanize::relative(delta));
+    Ok(())
+}
+
+/// Prints the time from one expression to another in a unit. Numbers are negative if the
+/// second expression is before the first.
+pub fn diff(args: &DiffArgs, config: &ParserConfig) -> Result<()> {
+    let (from, to) = diff_endpoints(&args.words, config)?;
+
+    let delta = to.signed_duration_since(from);
+    let text = match args.unit {
+        DiffUnit::Days => delta.num_days().to_string(),
+        DiffUnit::BusinessDays => {
+            date_parser::business_days_between(from.date_naive(), to.date_naive(), config)
+                .map_err(|e| parse_failure(&args.words.join(" "), &e, Format::Rfc3339))?
+                .to_string()
+        }
+        DiffUnit::Hours => delta.num_hours().to_string(),
+        DiffUnit::Weeks => delta.num_weeks().to_string(),
+        DiffUnit::Human => humanize::duration(delta),
+    };
+    println!("{}", text);
+    Ok(())
+}
+
+/// Splits the words of `diff` into its two expressions and resolves them. The words are
+/// split at the first "to" or "and" that leaves an expression on either side, so that
+/// "today to in 2 days and 3 hours" splits at "to", or else at the first place that does,
+/// so that "today end of quarter" reads as "today" and "end of quarter".
+fn diff_endpoints(
+    words: &[String],
+    config: &ParserConfig,
+) -> Result<(DateTime<FixedOffset>, DateTime<FixedOffset>)> {
+    let parse = |words: &[String]| {
+        let input = words.join(" ");
+        date_parser::parse_with_config(&input, config)
+            .map(|parsed| parsed.datetime)
+            .map_err(|e| (input, e))
+    };
+
+    let separators = (1..words.len() - 1)
+        .filter(|&i| words[i].eq_ignore_ascii_case("to") || words[i].eq_ignore_ascii_case("and"))
+        .map(|i| (&words[..i], &words[i + 1..]));
+    let splits = separators.chain((1..words.len()).map(|i| (&words[..i], &words[i..])));
+
+    let mut failure = None;
+    for (from, to) in splits {
+        match (parse(from), parse(to)) {
+            (Ok(from), Ok(to)) => return Ok((from, to)),
+            (Err(e), _) | (_, Err(e)) => {
+                failure.get_or_insert(e);
+            }
+        }
+    }
+    match failure {
+        Some((input, e)) => Err(parse_failure(&input, &e, Format::Rfc3339)),
+        None => Err(Error::Usage(anyhow!("diff takes two expressions"))),
+    }
+}
+
+/// Prints the occurrences of a recurring date after the reference time, after `--after`
+/// or within `--between`, one per line.
+pub fn occurrences(args: &OccurrencesArgs, config: &ParserConfig) -> Result<()> {
+    let (format, pattern) = args.output.resolve()?;
+    let pattern = pattern.as_deref();
+
+    let mut config = config.clone();
+    let mut end = None;
+    if let Some(after) = &args.after {
+        config.now = Some(context::resolve_time(after, &config).map_err(Error::Usage)?);
+    }
+    if let Some([start, until]) = args.between.as_deref() {
+        let start = context::resolve_time(start, &config).map_err(Error::Usage)?;
+        let until = context::resolve_time(until, &config).map_err(Error::Usage)?;
+        if until <= start {
+            return Err(Error::Usage(anyhow!(
+                "The end of --between must be after its start"
+            )));
+        }
+        config.now = Some(start);
+        end = Some(until);
+    }
+    let count = match (args.count, end) {
+        (Some(count), _) => count as usize,
+        (None, Some(_)) => usize::MAX,
+        (None, None) => 5,
+    };
+    let config = &config;
+
+    let input = args.expression.text();
+    let (recurrence, occurrences) = date_parser::recurrence_from_string_with_config(&input, config)
+        .and_then(|mut recurrence| {
+            if end.is_some() {
+                recurrence.include_reference_time(&config.reference_time(), config)?;
+            }
+            let occurrences = recurrence
+                .occurrences(config)
+                .take_while(|occurrence| match (occurrence, end) {
+                    (Ok(datetime), Some(end)) => *datetime < end,
+                    _ => true,
+                })
+                .take(count)
+                .map(|occurrence| {
+                    occurrence.map(|datetime| date_parser::to_target_zone(&datetime, config))
+                })
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            Ok((recurrence, occurrences))
+        })
+        .map_err(|e| parse_failure(&input, &e, format))?;
+
+    for datetime in occurrences {
+        let parsed = ParsedDate {
+            datetime,
+            source_zone: recurrence.source_zone.clone(),
+            dst_resolutions: Vec::new(),
+            uncertainty: None,
+            granularity: recurrence.granularity,
+        };
+        println!("{}", output::render(&input, &parsed, format, pattern, &[])?);
+    }
+    Ok(())
+}
+
+/// Prints how an expression is resolved: the result, the rules that matched and any
+/// warnings.
+pub fn explain(args: &ExpressionArgs, config: &ParserConfig) -> Result<()> {
+    let input = args.text();
+    let text =
+        explain::explain(&input, config).map_err(|e| parse_failure(&input, &e, Format::Rfc3339))?;
+    println!("{}", text);
+    Ok(())
+}
+
+/// Prints a parse error for `input` to stderr in `format`.
+fn parse_failure(input: &str, error: &ParseDateError, format: Format) -> Error {
+    eprintln!("{}", output::render_error(input, error, format));
+    Error::Parse
+}
@@ -0,0 +1,89 @@
+//! The reference time and time zone expressions are resolved in.
+
+use anyhow::anyhow;
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime, TimeZone, Weekday};
+use chrono_tz::Tz;
+use natural_date_parser::holidays::HolidayCalendar;
+use natural_date_parser::zones::Zone;
+use natural_date_parser::{DateOrder, ParserConfig, date_parser};
+use std::sync::Arc;
+
+/// Parses the zone expressions are resolved in instead of the local time zone: an IANA
+/// zone such as "Europe/Kyiv", or "UTC".
+pub fn reference_zone(zone: &str) -> anyhow::Result<Tz> {
+    zone.parse::<Tz>()
+        .map_err(|_| anyhow!("Unknown time zone: {:?}", zone))
+}
+
+/// Parses the zone results are converted to before they are printed: an IANA zone such
+/// as "America/New_York", or "UTC".
+pub fn target_zone(zone: &str) -> anyhow::Result<Zone> {
+    zone.parse::<Tz>()
+        .map(Zone::Named)
+        .map_err(|_| anyhow!("Unknown time zone: {:?}", zone))
+}
+
+/// Resolves a reference time given as an RFC 3339 timestamp, such as
+/// "2026-01-15T09:00:00+01:00", as a date, such as "2026-01-15", which stands for its
+/// midnight in the reference zone, or as an expression resolved from the current time, such as
+/// "next Monday at 9am".
+pub fn reference_time(value: &str, config: &ParserConfig) -> anyhow::Result<DateTime<FixedOffset>> {
+    let config = ParserConfig {
+        now: None,
+        ..config.clone()
+    };
+    resolve_time(value, &config)
+}
+
+/// Resolves a point in time given in any of the forms accepted by [`reference_time`],
+/// with expressions resolved from the reference time of `config`.
+pub fn resolve_time(value: &str, config: &ParserConfig) -> anyhow::Result<DateTime<FixedOffset>> {
+    if let Ok(time) = DateTime::parse_from_rfc3339(value.trim()) {
+        return Ok(time);
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d") {
+        return config
+            .reference_zone()
+            .from_local_datetime(&date.and_time(NaiveTime::MIN))
+            .earliest()
+            .map(|midnight| midnight.fixed_offset())
+            .ok_or_else(|| anyhow!("Invalid time {:?}: there is no local midnight", value));
+    }
+
+    date_parser::parse_with_config(value, config)
+        .map(|parsed| parsed.datetime)
+        .map_err(|e| anyhow!("Invalid time {:?}: {}", value, e))
+}
+
+/// Locales accepted by [`apply_locale`].
+pub const LOCALES: [&str; 2] = ["en-US", "en-GB"];
+
+/// Applies the conventions of `locale` to `config`: the order of numeric dates, the first
+/// day of the week and the holiday calendar. Returns the canonical name of the locale, one
+/// of [`LOCALES`].
+pub fn apply_locale(config: &mut ParserConfig, locale: &str) -> anyhow::Result<&'static str> {
+    match locale
+        .trim()
+        .replace('_', "-")
+        .to_ascii_lowercase()
+        .as_str()
+    {
+        "en" | "en-us" => {
+            config.readings.date_order = DateOrder::MonthFirst;
+            config.week_start = Weekday::Sun;
+            config.holidays = Arc::new(HolidayCalendar::united_states());
+            Ok(LOCALES[0])
+        }
+        "en-gb" => {
+            config.readings.date_order = DateOrder::DayFirst;
+            config.week_start = Weekday::Mon;
+            config.holidays = Arc::new(HolidayCalendar::united_kingdom());
+            Ok(LOCALES[1])
+        }
+        _ => Err(anyhow!(
+            "Unknown locale {:?}; expected one of {}",
+            locale,
+            LOCALES.join(", ")
+        )),
+    }
+}
@@ -0,0 +1,48 @@
+//! Failures of the command line interface and the exit statuses they map to.
+
+use thiserror::Error;
+
+/// Exit status when an expression could not be parsed.
+pub const PARSE_ERROR: u8 = 1;
+
+/// Exit status when the arguments are invalid. clap uses the same status for the
+/// arguments it rejects itself.
+pub const USAGE_ERROR: u8 = 2;
+
+/// Exit status for any other failure, such as an input file that cannot be read.
+pub const FAILURE: u8 = 3;
+
+/// Why a command failed.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// One or more expressions could not be parsed. The errors have been printed
+    /// already, in the output format of the command.
+    #[error("Some expressions could not be parsed")]
+    Parse,
+    /// The arguments are invalid, such as an unknown time zone or format pattern.
+    #[error(transparent)]
+    Usage(anyhow::Error),
+    /// Any other failure.
+    #[error(transparent)]
+    Other(#[from] anyhow::Error),
+}
+
+impl From<std::io::Error> for Error {
+    fn from(error: std::io::Error) -> Self {
+        Error::Other(error.into())
+    }
+}
+
+impl Error {
+    /// The exit status of the process for this failure.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::Parse => PARSE_ERROR,
+            Error::Usage(_) => USAGE_ERROR,
+            Error::Other(_) => FAILURE,
+        }
+    }
+}
+
+/// The result of a command.
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,51 @@
+//! The `explain` command: how an expression is resolved.
+
+use crate::cli::output;
+use crate::cli::tree::render_tree;
+use natural_date_parser::{DateParser, ParseDateError, ParserConfig, Rule, date_parser};
+use pest::Parser;
+
+/// Shows how `input` is resolved: the result with its weekday, the rules that matched with
+/// their spans, each intermediate datetime produced while resolving it, and any warnings.
+///
+/// ```text
+/// 2024-11-12T17:00:00+00:00 (Tuesday)
+/// date_expression 0..16 "tomorrow at 5 PM"
+///   relative_day_and_specific_time 0..16 "tomorrow at 5 PM"
+///   ...
+/// step: reference_time ParserConfig::now -> 2024-11-11T12:00:00+00:00 (Monday)
+/// step: relative_term "tomorrow" -> 2024-11-12T12:00:00+00:00 (Tuesday)
+/// step: process_time_of_day 2024-11-12 17:00:00 -> 2024-11-12T17:00:00+00:00 (Tuesday)
+/// step: relative_day_and_specific_time "tomorrow at 5 PM" -> 2024-11-12T17:00:00+00:00 (Tuesday)
+/// ```
+pub fn explain(input: &str, config: &ParserConfig) -> Result<String, ParseDateError> {
+    let (parsed, steps) = date_parser::trace(|| date_parser::parse_with_config(input, config));
+    let parsed = parsed?;
+
+    let mut text = format!(
+        "{} ({})\n",
+        parsed.datetime.to_rfc3339(),
+        parsed.datetime.format("%A")
+    );
+    if let Some(pair) = DateParser::parse(Rule::date_expression, input)
+        .ok()
+        .and_then(|mut pairs| pairs.next())
+    {
+        text.push_str(&render_tree(pair));
+    }
+    for step in steps {
+        text.push_str(&format!(
+            "step: {} {} -> {} ({})\n",
+            step.name,
+            step.detail,
+            step.datetime.fixed_offset().to_rfc3339(),
+            step.datetime.format("%A")
+        ));
+    }
+    let other_readings = output::other_readings(input, &parsed, config);
+    for warning in output::warnings(input, &parsed, &other_readings) {
+        text.push_str(&format!("warning: {}\n", warning));
+    }
+
+    Ok(text.trim_end().to_string())
+}
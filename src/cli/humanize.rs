@@ -0,0 +1,43 @@
+//! Descriptions of lengths of time for people, such as "in 3 days".
+
+use chrono::Duration;
+
+/// Units used to describe a length of time, from the largest, with their length in
+/// seconds. Months and years are counted as 30 and 365 days.
+const UNITS: [(&str, i64); 5] = [
+    ("year", 365 * 86_400),
+    ("month", 30 * 86_400),
+    ("day", 86_400),
+    ("hour", 3_600),
+    ("minute", 60),
+];
+
+/// Describes the length of `duration` in a single unit, rounded to the nearest whole
+/// one, such as "3 days" or "1 hour". A unit is used from 90% of its length, so 23 hours
+/// and 50 minutes is "1 day". The sign of `duration` is ignored.
+pub fn duration(duration: Duration) -> String {
+    let seconds = duration.num_seconds().abs();
+
+    for (name, size) in UNITS {
+        if seconds * 10 >= size * 9 {
+            let count = ((seconds + size / 2) / size).max(1);
+            return format!("{} {}{}", count, name, if count == 1 { "" } else { "s" });
+        }
+    }
+
+    "less than a minute".to_string()
+}
+
+/// Describes when something happens `delta` from now, such as "in 3 days", "2 hours ago"
+/// or "now" for less than a minute either way.
+pub fn relative(delta: Duration) -> String {
+    if delta.num_seconds().abs() * 10 < 60 * 9 {
+        return "now".to_string();
+    }
+
+    if delta > Duration::zero() {
+        format!("in {}", duration(delta))
+    } else {
+        format!("{} ago", duration(delta))
+    }
+}
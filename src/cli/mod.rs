@@ -0,0 +1,11 @@
+//! Building blocks of the `natural-date-parser` command line interface.
+
+pub mod batch;
+pub mod commands;
+pub mod context;
+pub mod error;
+pub mod explain;
+pub mod humanize;
+pub mod output;
+pub mod repl;
+pub mod tree;
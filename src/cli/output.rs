@@ -0,0 +1,376 @@
+//! Output formats of the command line interface.
+//!
+//! With `--format json`, every result is printed to stdout as one JSON object:
+//!
+//! ```json
+//! {
+//!   "input": "tomorrow at 3pm EST",
+//!   "datetime": "2026-10-19T22:00:00+02:00",
+//!   "timestamp": 1792440000,
+//!   "granularity": "time",
+//!   "zone": { "offset": "+02:00", "source": "EST", "source_zone": "UTC-05:00" },
+//!   "uncertainty": null,
+//!   "warnings": []
+//! }
+//! ```
+//!
+//! `granularity` is `"time"` or `"day"`, `zone.source` and `zone.source_zone` are `null`
+//! when the expression has no time zone, and `uncertainty` is an object with `start` and
+//! `end` for approximate expressions such as "around 3pm". Errors are printed to stderr as
+//! `{ "input": ..., "error": { "kind": "parse", "message": ... } }` for an expression
+//! that could not be parsed, and as `{ "error": { "kind": ..., "message": ... } }` for
+//! anything else, with `kind` `"usage"` for invalid arguments and `"failure"` for other
+//! errors, such as an unreadable input file.
+
+use crate::cli::error::Error;
+use anyhow::anyhow;
+use chrono::format::StrftimeItems;
+use chrono::{DateTime, FixedOffset};
+use clap::ValueEnum;
+use natural_date_parser::zones::{DstResolution, ReferenceZone};
+use natural_date_parser::{
+    DateRange, Granularity, ParseDateError, ParsedDate, ParserConfig, Rule, date_parser,
+};
+use serde_json::{Value, json};
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// A JSON object with the input, the instant, its granularity, the zone and warnings.
+    Json,
+    /// The instant as an RFC 3339 timestamp, for example "2026-10-19T15:00:00+02:00".
+    Rfc3339,
+    /// Seconds since the Unix epoch.
+    Unix,
+    /// The instant formatted with the strftime-style `--pattern`.
+    Custom,
+    /// The instant in RFC 2822 format, for example "Mon, 19 Oct 2026 15:00:00 +0200".
+    Rfc2822,
+    /// Milliseconds since the Unix epoch.
+    EpochMs,
+}
+
+/// Names accepted by `--output` instead of a pattern.
+pub const PRESETS: [&str; 5] = ["date", "time", "iso", "rfc2822", "epoch-ms"];
+
+/// Resolves the value of `--output`, a preset from [`PRESETS`] or a strftime-style pattern,
+/// to a format and its pattern. A value without any "%" directive that is not a preset is
+/// rejected, since it would print the same text for every result.
+pub fn output_format(output: &str) -> anyhow::Result<(Format, Option<String>)> {
+    match output {
+        "date" => Ok((Format::Custom, Some("%Y-%m-%d".to_string()))),
+        "time" => Ok((Format::Custom, Some("%H:%M:%S".to_string()))),
+        "iso" => Ok((Format::Rfc3339, None)),
+        "rfc2822" => Ok((Format::Rfc2822, None)),
+        "epoch-ms" => Ok((Format::EpochMs, None)),
+        pattern if pattern.contains('%') => {
+            check_pattern(Format::Custom, Some(pattern))?;
+            Ok((Format::Custom, Some(pattern.to_string())))
+        }
+        _ => Err(anyhow!(
+            "Unknown output {:?}; expected a pattern such as \"%Y-%m-%d\" or one of {}",
+            output,
+            PRESETS.join(", ")
+        )),
+    }
+}
+
+/// How durations are printed by the `duration` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum DurationFormat {
+    /// An ISO 8601 duration, for example "PT2H30M".
+    Iso,
+    /// The number of seconds from the reference time to the end of the duration.
+    Seconds,
+    /// A JSON object with the input, the ISO 8601 duration, its length in seconds and the
+    /// instants it starts and ends at.
+    Json,
+}
+
+/// Checks that `pattern` is given with [`Format::Custom`] and is a valid strftime-style
+/// pattern.
+pub fn check_pattern(format: Format, pattern: Option<&str>) -> anyhow::Result<()> {
+    if format == Format::Custom {
+        let pattern = pattern.ok_or_else(|| anyhow!("--format custom needs a --pattern"))?;
+        StrftimeItems::new(pattern)
+            .parse()
+            .map_err(|_| anyhow!("Invalid format pattern: {:?}", pattern))?;
+    }
+    Ok(())
+}
+
+/// Formats a parsed expression. `pattern` is required with [`Format::Custom`], and
+/// `other_readings` are only listed with [`Format::Json`].
+pub fn render(
+    input: &str,
+    parsed: &ParsedDate,
+    format: Format,
+    pattern: Option<&str>,
+    other_readings: &[DateTime<FixedOffset>],
+) -> anyhow::Result<String> {
+    match format {
+        Format::Json => Ok(to_json(input, parsed, other_readings).to_string()),
+        _ => render_datetime(&parsed.datetime, format, pattern),
+    }
+}
+
+/// Formats a single instant. With [`Format::Json`], the instant is a JSON string in RFC
+/// 3339 format.
+pub fn render_datetime(
+    datetime: &DateTime<FixedOffset>,
+    format: Format,
+    pattern: Option<&str>,
+) -> anyhow::Result<String> {
+    match format {
+        Format::Json => Ok(json!(datetime.to_rfc3339()).to_string()),
+        Format::Rfc3339 => Ok(datetime.to_rfc3339()),
+        Format::Unix => Ok(datetime.timestamp().to_string()),
+        Format::Rfc2822 => Ok(datetime.to_rfc2822()),
+        Format::EpochMs => Ok(datetime.timestamp_millis().to_string()),
+        Format::Custom => {
+            let pattern = pattern.ok_or_else(|| anyhow!("--format custom needs a --pattern"))?;
+            let items = StrftimeItems::new(pattern)
+                .parse()
+                .map_err(|_| anyhow!("Invalid format pattern: {:?}", pattern))?;
+            Ok(datetime.format_with_items(items.iter()).to_string())
+        }
+    }
+}
+
+/// Formats the range a period covers as `start/end`, like an ISO 8601 interval, or as a
+/// JSON object with the input, `start` and `end`. The range is half-open: `end` is the
+/// first instant after it.
+pub fn render_range(
+    input: &str,
+    range: &DateRange,
+    format: Format,
+    pattern: Option<&str>,
+    config: &ParserConfig,
+) -> anyhow::Result<String> {
+    let start = date_parser::to_target_zone(&range.start, config);
+    let end = date_parser::to_target_zone(&range.end, config);
+
+    match format {
+        Format::Json => Ok(json!({
+            "input": input,
+            "start": start.to_rfc3339(),
+            "end": end.to_rfc3339(),
+        })
+        .to_string()),
+        _ => Ok(format!(
+            "{}/{}",
+            render_datetime(&start, format, pattern)?,
+            render_datetime(&end, format, pattern)?
+        )),
+    }
+}
+
+/// Formats the duration given by `offsets`, which starts at `start` and ends at `end`.
+pub fn render_duration(
+    input: &str,
+    offsets: &[(i32, Rule)],
+    start: DateTime<ReferenceZone>,
+    end: DateTime<ReferenceZone>,
+    format: DurationFormat,
+    config: &ParserConfig,
+) -> Result<String, ParseDateError> {
+    let seconds = end.signed_duration_since(start).num_seconds();
+
+    match format {
+        DurationFormat::Iso => iso_duration(offsets, start, config),
+        DurationFormat::Seconds => Ok(seconds.to_string()),
+        DurationFormat::Json => Ok(json!({
+            "input": input,
+            "duration": iso_duration(offsets, start, config)?,
+            "seconds": seconds,
+            "start": date_parser::to_target_zone(&start, config).to_rfc3339(),
+            "end": date_parser::to_target_zone(&end, config).to_rfc3339(),
+        })
+        .to_string()),
+    }
+}
+
+/// Writes amount/unit pairs as an ISO 8601 duration, such as "P1Y2M" or "PT2H30M".
+///
+/// Weeks are written with "W" only when the duration has no other date parts, and are
+/// counted as days otherwise. Business days have no fixed length, so they are counted as
+/// the calendar days they span from `start`.
+pub fn iso_duration(
+    offsets: &[(i32, Rule)],
+    start: DateTime<ReferenceZone>,
+    config: &ParserConfig,
+) -> Result<String, ParseDateError> {
+    let (mut years, mut months, mut weeks, mut days, mut hours, mut minutes) = (0, 0, 0, 0, 0, 0);
+
+    for &(amount, unit) in offsets {
+        let amount = amount as i64;
+        match unit {
+            Rule::year_s => years += amount,
+            Rule::month_s => months += amount,
+            Rule::week_s => weeks += amount,
+            Rule::day_s => days += amount,
+            Rule::business_day_s => {
+                let date = start.date_naive();
+                days += (date_parser::shift_business_days(date, amount, config)? - date).num_days();
+            }
+            Rule::hour_s => hours += amount,
+            Rule::minute_s => minutes += amount,
+            _ => return Err(ParseDateError::ParseError("Invalid time unit".to_string())),
+        }
+    }
+
+    if years != 0 || months != 0 || days != 0 {
+        days += weeks * 7;
+        weeks = 0;
+    }
+
+    let mut text = "P".to_string();
+    for (value, designator) in [(years, 'Y'), (months, 'M'), (weeks, 'W'), (days, 'D')] {
+        if value != 0 {
+            text.push_str(&format!("{}{}", value, designator));
+        }
+    }
+    if hours != 0 || minutes != 0 {
+        text.push('T');
+        for (value, designator) in [(hours, 'H'), (minutes, 'M')] {
+            if value != 0 {
+                text.push_str(&format!("{}{}", value, designator));
+            }
+        }
+    }
+    if text == "P" {
+        text.push_str("T0S");
+    }
+
+    Ok(text)
+}
+
+/// Formats an error for stderr: a JSON object with [`Format::Json`] and a line of text
+/// otherwise.
+pub fn render_error(input: &str, error: &ParseDateError, format: Format) -> String {
+    let ParseDateError::ParseError(message) = error;
+
+    match format {
+        Format::Json => json!({
+            "input": input,
+            "error": { "kind": "parse", "message": message },
+        })
+        .to_string(),
+        _ => format!("Error: {}", error),
+    }
+}
+
+/// Formats a failure other than a parse error for stderr, the way [`render_error`] does.
+pub fn render_failure(error: &Error, format: Format) -> String {
+    match format {
+        Format::Json => json!({
+            "error": {
+                "kind": match error {
+                    Error::Usage(_) => "usage",
+                    _ => "failure",
+                },
+                "message": format!("{:#}", error),
+            },
+        })
+        .to_string(),
+        _ => format!("Error: {:#}", error),
+    }
+}
+
+/// Builds the JSON object printed with [`Format::Json`].
+pub fn to_json(
+    input: &str,
+    parsed: &ParsedDate,
+    other_readings: &[DateTime<FixedOffset>],
+) -> Value {
+    json!({
+        "input": input,
+        "datetime": parsed.datetime.to_rfc3339(),
+        "timestamp": parsed.datetime.timestamp(),
+        "granularity": match parsed.granularity {
+            Granularity::Time => "time",
+            Granularity::Day => "day",
+        },
+        "zone": {
+            "offset": parsed.datetime.offset().to_string(),
+            "source": parsed.source_zone.as_ref().map(|zone| zone.text.clone()),
+            "source_zone": parsed.source_zone.as_ref().map(|zone| zone.zone.to_string()),
+        },
+        "uncertainty": parsed.uncertainty.map(|uncertainty| json!({
+            "start": uncertainty.start.to_rfc3339(),
+            "end": uncertainty.end.to_rfc3339(),
+        })),
+        "warnings": warnings(input, parsed, other_readings),
+    })
+}
+
+/// Returns the instants the other readings of `input` resolve to, such as April 3 for
+/// "03/04/2025" read with the month first. This parses `input` once more with every
+/// reading, so it is only worth calling for output that lists warnings.
+pub fn other_readings(
+    input: &str,
+    parsed: &ParsedDate,
+    config: &ParserConfig,
+) -> Vec<DateTime<FixedOffset>> {
+    date_parser::parse_all_with_config(input, config)
+        .map(|interpretations| {
+            interpretations
+                .into_iter()
+                .map(|interpretation| interpretation.parsed.datetime)
+                .filter(|datetime| *datetime != parsed.datetime)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Lists what the user may want to double-check about a result: daylight saving time
+/// adjustments, ambiguous zone abbreviations and `other_readings` of the expression.
+pub fn warnings(
+    input: &str,
+    parsed: &ParsedDate,
+    other_readings: &[DateTime<FixedOffset>],
+) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    for resolution in &parsed.dst_resolutions {
+        warnings.push(
+            match resolution {
+                DstResolution::Earliest => {
+                    "The time occurs twice because of daylight saving time; using the earlier one"
+                }
+                DstResolution::Latest => {
+                    "The time occurs twice because of daylight saving time; using the later one"
+                }
+                DstResolution::ShiftedForward => {
+                    "The time is skipped by daylight saving time; shifted forward"
+                }
+                DstResolution::ShiftedBack => {
+                    "The time is skipped by daylight saving time; shifted back"
+                }
+            }
+            .to_string(),
+        );
+    }
+
+    if let Some(zone) = &parsed.source_zone
+        && !zone.alternatives.is_empty()
+    {
+        let alternatives: Vec<String> = zone.alternatives.iter().map(|z| z.to_string()).collect();
+        warnings.push(format!(
+            "{:?} is ambiguous; using {} rather than {}",
+            zone.text,
+            zone.zone,
+            alternatives.join(", ")
+        ));
+    }
+
+    if !other_readings.is_empty() {
+        let others: Vec<String> = other_readings
+            .iter()
+            .map(|datetime| datetime.to_rfc3339())
+            .collect();
+        warnings.push(format!("{:?} could also mean {}", input, others.join(", ")));
+    }
+
+    warnings
+}
@@ -0,0 +1,139 @@
+//! The interactive `repl` command.
+
+use crate::cli::context;
+use crate::cli::explain::explain;
+use anyhow::anyhow;
+use natural_date_parser::ParserConfig;
+use rustyline::DefaultEditor;
+use rustyline::error::ReadlineError;
+use std::path::PathBuf;
+
+const HELP: &str = "\
+Type an expression to see how it is resolved, or one of these commands:
+  :now [TIME|reset]  shows or sets the reference time (RFC 3339 or an expression)
+  :tz [ZONE]         shows or sets the IANA time zone expressions are resolved in
+  :locale [LOCALE]   shows or sets the locale (en-US or en-GB)
+  :help              shows this help
+  :quit              exits";
+
+/// What the REPL does after a line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    /// Prints the text and reads the next line.
+    Output(String),
+    /// Exits.
+    Quit,
+}
+
+/// The context expressions are resolved in, changed by the `:now`, `:tz` and `:locale`
+/// commands.
+pub struct Session {
+    /// Options expressions are parsed with, including the zone set with `--tz` or `:tz`.
+    pub config: ParserConfig,
+    /// The locale set with `:locale`.
+    pub locale: &'static str,
+}
+
+impl Session {
+    /// Starts a session with `config`.
+    pub fn new(config: ParserConfig) -> Self {
+        Self {
+            config,
+            locale: context::LOCALES[0],
+        }
+    }
+
+    /// Handles one line: a command starting with ":" or an expression.
+    pub fn handle(&mut self, line: &str) -> Reply {
+        let line = line.trim();
+        let (command, argument) = match line.split_once(char::is_whitespace) {
+            Some((command, argument)) => (command, Some(argument.trim())),
+            None => (line, None),
+        };
+
+        let result = match command {
+            "" => Ok(String::new()),
+            ":q" | ":quit" | ":exit" => return Reply::Quit,
+            ":help" => Ok(HELP.to_string()),
+            ":now" => self.now(argument),
+            ":tz" => self.tz(argument),
+            ":locale" => self.locale(argument),
+            _ if command.starts_with(':') => Err(anyhow!(
+                "Unknown command {:?}; type :help for the list of commands",
+                command
+            )),
+            _ => explain(line, &self.config).map_err(Into::into),
+        };
+
+        Reply::Output(result.unwrap_or_else(|e| format!("Error: {}", e)))
+    }
+
+    fn now(&mut self, argument: Option<&str>) -> anyhow::Result<String> {
+        match argument {
+            None => {}
+            Some("reset") => self.config.now = None,
+            Some(time) => self.config.now = Some(context::reference_time(time, &self.config)?),
+        }
+
+        Ok(match self.config.now {
+            Some(_) => format!("now: {}", self.config.reference_time().to_rfc3339()),
+            None => format!(
+                "now: current time ({})",
+                self.config.reference_time().to_rfc3339()
+            ),
+        })
+    }
+
+    fn tz(&mut self, argument: Option<&str>) -> anyhow::Result<String> {
+        if let Some(zone) = argument {
+            self.config.zone = Some(context::reference_zone(zone)?);
+        }
+
+        let offset = self.config.reference_zone().now().offset().to_string();
+        Ok(match &self.config.zone {
+            Some(zone) => format!("tz: {} ({})", zone, offset),
+            None => format!("tz: system ({})", offset),
+        })
+    }
+
+    fn locale(&mut self, argument: Option<&str>) -> anyhow::Result<String> {
+        if let Some(locale) = argument {
+            self.locale = context::apply_locale(&mut self.config, locale)?;
+        }
+
+        Ok(format!("locale: {}", self.locale))
+    }
+}
+
+/// Reads lines until `:quit` or the end of the input, keeping the history in
+/// `~/.natural_date_parser_history`.
+pub fn run(mut session: Session) -> anyhow::Result<()> {
+    let mut editor = DefaultEditor::new()?;
+    let history = std::env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join(".natural_date_parser_history"));
+    if let Some(history) = &history {
+        let _ = editor.load_history(history);
+    }
+
+    loop {
+        let line = match editor.readline("> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(e) => return Err(e.into()),
+        };
+        if !line.trim().is_empty() {
+            let _ = editor.add_history_entry(line.as_str());
+        }
+
+        match session.handle(&line) {
+            Reply::Output(text) if text.is_empty() => {}
+            Reply::Output(text) => println!("{}", text),
+            Reply::Quit => break,
+        }
+    }
+
+    if let Some(history) = &history {
+        let _ = editor.save_history(history);
+    }
+    Ok(())
+}
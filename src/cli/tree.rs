@@ -0,0 +1,30 @@
+//! Text rendering of pest parse trees.
+
+use natural_date_parser::Rule;
+use pest::iterators::Pair;
+use std::fmt::Write;
+
+/// Renders `pair` and every rule matched inside it, one per line and indented by depth,
+/// with the span and text each rule matched, for example `relative_term 0..8 "tomorrow"`.
+pub fn render_tree(pair: Pair<'_, Rule>) -> String {
+    let mut tree = String::new();
+    write_pair(&mut tree, pair, 0);
+    tree
+}
+
+fn write_pair(tree: &mut String, pair: Pair<'_, Rule>, depth: usize) {
+    let span = pair.as_span();
+    let _ = writeln!(
+        tree,
+        "{}{:?} {}..{} {:?}",
+        "  ".repeat(depth),
+        pair.as_rule(),
+        span.start(),
+        span.end(),
+        span.as_str()
+    );
+
+    for inner_pair in pair.into_inner() {
+        write_pair(tree, inner_pair, depth + 1);
+    }
+}
@@ -0,0 +1,285 @@
+//! Holiday calendars used to resolve named days such as "Christmas" or "Easter Monday".
+//!
+//! Any type implementing [`HolidayProvider`] can be set as `ParserConfig::holidays`.
+//! [`HolidayCalendar`] is a rule-based provider that supports fixed-date holidays,
+//! holidays on the nth weekday of a month and holidays relative to Easter.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::fmt::Debug;
+
+/// A source of holiday dates.
+pub trait HolidayProvider: Debug + Send + Sync {
+    /// Returns the date of the holiday called `name` in `year`, or `None` if the provider
+    /// does not know the name. Names are compared after [`normalize_name`].
+    fn date_of(&self, name: &str, year: i32) -> Option<NaiveDate>;
+
+    /// Returns the main name and date of every holiday in `year`.
+    ///
+    /// Business-day arithmetic looks holidays up here, once per year, so a provider that
+    /// overrides [`HolidayProvider::is_holiday`] must return the same dates.
+    fn holidays_in(&self, year: i32) -> Vec<(String, NaiveDate)>;
+
+    /// Returns `true` if `date` is a holiday.
+    fn is_holiday(&self, date: NaiveDate) -> bool {
+        self.holidays_in(date.year())
+            .iter()
+            .any(|(_, holiday)| *holiday == date)
+    }
+}
+
+/// How the date of a holiday is computed for a given year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HolidayRule {
+    /// The same month and day every year, for example December 25.
+    Fixed { month: u32, day: u32 },
+    /// The nth weekday of a month, for example the fourth Thursday of November.
+    /// Negative values count from the end of the month, so -1 is the last one.
+    NthWeekday { month: u32, weekday: Weekday, n: i8 },
+    /// A number of days after (or before, if negative) Western Easter Sunday.
+    EasterOffset(i64),
+}
+
+impl HolidayRule {
+    /// Returns the date of the rule in `year`, or `None` if it does not exist that year.
+    pub fn date_in(&self, year: i32) -> Option<NaiveDate> {
+        match *self {
+            HolidayRule::Fixed { month, day } => NaiveDate::from_ymd_opt(year, month, day),
+            HolidayRule::NthWeekday { month, weekday, n } if n > 0 => {
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n as u8)
+            }
+            HolidayRule::NthWeekday { month, weekday, n } if n < 0 => {
+                let last = crate::date_parser::last_weekday_of_month(year, month, weekday)?;
+                last.checked_sub_signed(Duration::weeks(i64::from(n.unsigned_abs() - 1)))
+                    .filter(|date| date.month() == month)
+            }
+            HolidayRule::NthWeekday { .. } => None,
+            HolidayRule::EasterOffset(days) => {
+                easter_sunday(year)?.checked_add_signed(Duration::days(days))
+            }
+        }
+    }
+}
+
+/// A named holiday. The first name is the main one; the others are aliases.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Holiday {
+    /// Names the holiday can be referred to by, for example "Christmas Day" and
+    /// "Christmas".
+    pub names: Vec<String>,
+    /// How the date of the holiday is computed.
+    pub rule: HolidayRule,
+}
+
+/// A rule-based [`HolidayProvider`].
+///
+/// ```
+/// use natural_date_parser::holidays::{HolidayCalendar, HolidayProvider, HolidayRule};
+///
+/// let mut calendar = HolidayCalendar::new();
+/// calendar.add(&["Company Day"], HolidayRule::Fixed { month: 6, day: 1 });
+///
+/// assert!(calendar.date_of("company day", 2026).is_some());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HolidayCalendar {
+    /// Holidays known to the calendar.
+    pub holidays: Vec<Holiday>,
+}
+
+impl HolidayCalendar {
+    /// Creates an empty calendar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a holiday known by `names`, the first of which is its main name.
+    pub fn add(&mut self, names: &[&str], rule: HolidayRule) {
+        self.holidays.push(Holiday {
+            names: names.iter().map(|name| name.to_string()).collect(),
+            rule,
+        });
+    }
+
+    /// United States federal holidays, along with Easter, Christmas Eve, New Year's Eve,
+    /// Valentine's Day and Halloween. This is the default calendar.
+    ///
+    /// Dates are the holidays themselves, not the weekdays they are observed on.
+    pub fn united_states() -> Self {
+        use HolidayRule::*;
+
+        let mut calendar = Self::new();
+        calendar.add(&["New Year's Day", "New Year"], Fixed { month: 1, day: 1 });
+        calendar.add(
+            &["Martin Luther King Jr. Day", "MLK Day"],
+            NthWeekday {
+                month: 1,
+                weekday: Weekday::Mon,
+                n: 3,
+            },
+        );
+        calendar.add(&["Valentine's Day"], Fixed { month: 2, day: 14 });
+        calendar.add(
+            &["Presidents' Day", "Washington's Birthday"],
+            NthWeekday {
+                month: 2,
+                weekday: Weekday::Mon,
+                n: 3,
+            },
+        );
+        calendar.add(&["Good Friday"], EasterOffset(-2));
+        calendar.add(&["Easter", "Easter Sunday"], EasterOffset(0));
+        calendar.add(&["Easter Monday"], EasterOffset(1));
+        calendar.add(
+            &["Memorial Day"],
+            NthWeekday {
+                month: 5,
+                weekday: Weekday::Mon,
+                n: -1,
+            },
+        );
+        calendar.add(&["Juneteenth"], Fixed { month: 6, day: 19 });
+        calendar.add(
+            &["Independence Day", "July Fourth"],
+            Fixed { month: 7, day: 4 },
+        );
+        calendar.add(
+            &["Labor Day"],
+            NthWeekday {
+                month: 9,
+                weekday: Weekday::Mon,
+                n: 1,
+            },
+        );
+        calendar.add(
+            &["Columbus Day", "Indigenous Peoples' Day"],
+            NthWeekday {
+                month: 10,
+                weekday: Weekday::Mon,
+                n: 2,
+            },
+        );
+        calendar.add(&["Halloween"], Fixed { month: 10, day: 31 });
+        calendar.add(&["Veterans Day"], Fixed { month: 11, day: 11 });
+        calendar.add(
+            &["Thanksgiving", "Thanksgiving Day"],
+            NthWeekday {
+                month: 11,
+                weekday: Weekday::Thu,
+                n: 4,
+            },
+        );
+        calendar.add(&["Christmas Eve"], Fixed { month: 12, day: 24 });
+        calendar.add(
+            &["Christmas Day", "Christmas", "Xmas"],
+            Fixed { month: 12, day: 25 },
+        );
+        calendar.add(&["New Year's Eve"], Fixed { month: 12, day: 31 });
+        calendar
+    }
+
+    /// Bank holidays of England and Wales, along with Easter and Christmas Eve.
+    ///
+    /// Dates are the holidays themselves, not the weekdays they are observed on.
+    pub fn united_kingdom() -> Self {
+        use HolidayRule::*;
+
+        let mut calendar = Self::new();
+        calendar.add(&["New Year's Day", "New Year"], Fixed { month: 1, day: 1 });
+        calendar.add(&["Good Friday"], EasterOffset(-2));
+        calendar.add(&["Easter", "Easter Sunday"], EasterOffset(0));
+        calendar.add(&["Easter Monday"], EasterOffset(1));
+        calendar.add(
+            &["Early May Bank Holiday", "May Day"],
+            NthWeekday {
+                month: 5,
+                weekday: Weekday::Mon,
+                n: 1,
+            },
+        );
+        calendar.add(
+            &["Spring Bank Holiday"],
+            NthWeekday {
+                month: 5,
+                weekday: Weekday::Mon,
+                n: -1,
+            },
+        );
+        calendar.add(
+            &["Summer Bank Holiday"],
+            NthWeekday {
+                month: 8,
+                weekday: Weekday::Mon,
+                n: -1,
+            },
+        );
+        calendar.add(&["Christmas Eve"], Fixed { month: 12, day: 24 });
+        calendar.add(
+            &["Christmas Day", "Christmas", "Xmas"],
+            Fixed { month: 12, day: 25 },
+        );
+        calendar.add(&["Boxing Day"], Fixed { month: 12, day: 26 });
+        calendar
+    }
+}
+
+impl HolidayProvider for HolidayCalendar {
+    fn date_of(&self, name: &str, year: i32) -> Option<NaiveDate> {
+        let name = normalize_name(name);
+
+        self.holidays
+            .iter()
+            .find(|holiday| {
+                holiday
+                    .names
+                    .iter()
+                    .any(|candidate| normalize_name(candidate) == name)
+            })
+            .and_then(|holiday| holiday.rule.date_in(year))
+    }
+
+    fn holidays_in(&self, year: i32) -> Vec<(String, NaiveDate)> {
+        self.holidays
+            .iter()
+            .filter_map(|holiday| {
+                let name = holiday.names.first()?;
+                Some((name.clone(), holiday.rule.date_in(year)?))
+            })
+            .collect()
+    }
+}
+
+/// Normalizes a holiday name for comparison: lowercase, without apostrophes or periods
+/// and with single spaces, so "New Year's Day" matches "new years  day".
+pub fn normalize_name(name: &str) -> String {
+    name.split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| !matches!(c, '\'' | '’' | '.'))
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
+        })
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Returns the date of Western (Gregorian) Easter Sunday in `year`, computed with the
+/// anonymous Gregorian Computus.
+pub fn easter_sunday(year: i32) -> Option<NaiveDate> {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+
+    NaiveDate::from_ymd_opt(year, month as u32, day as u32)
+}
@@ -1,5 +1,15 @@
+use chrono::{DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveTime, TimeZone, Weekday};
+use chrono_tz::Tz;
+use holidays::{HolidayCalendar, HolidayProvider};
 use pest_derive::Parser;
+use std::sync::Arc;
 use thiserror::Error;
+use vocabulary::Vocabulary;
+use zones::{DstPolicy, DstResolution, ReferenceZone, SourceZone, Zone, ZoneAbbreviations};
+
+pub mod holidays;
+pub mod vocabulary;
+pub mod zones;
 
 /// A parser for date-related expressions using the `pest` parser library.
 #[derive(Parser)]
@@ -14,33 +24,558 @@ pub enum ParseDateError {
     ParseError(String),
 }
 
+/// A span of time, such as a quarter or a fiscal year.
+///
+/// The range is half-open: `start` is the first instant inside it and `end` is the first
+/// instant after it, so Q1 runs from January 1 00:00 up to (but not including) April 1
+/// 00:00.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    /// First instant of the range.
+    pub start: DateTime<ReferenceZone>,
+    /// First instant after the range.
+    pub end: DateTime<ReferenceZone>,
+}
+
+impl DateRange {
+    /// Returns `true` if `datetime` falls inside the range.
+    pub fn contains(&self, datetime: &DateTime<ReferenceZone>) -> bool {
+        self.start <= *datetime && *datetime < self.end
+    }
+}
+
+/// A deadline or a starting point, such as "by Friday" or "since March 3": an interval
+/// that is open on one side.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateBound {
+    /// The instant the interval ends or starts at, in `ParserConfig::target_zone`.
+    pub datetime: DateTime<FixedOffset>,
+    /// Whether the interval ends or starts at `datetime`.
+    pub kind: BoundKind,
+    /// Whether `datetime` itself is inside the interval.
+    pub inclusive: bool,
+}
+
+/// Which side of a [`DateBound`] is limited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundKind {
+    /// A deadline: the interval ends at the bound, as in "by Friday" or "before noon".
+    Upper,
+    /// A starting point: the interval starts at the bound, as in "after 5 PM" or "since
+    /// Monday".
+    Lower,
+}
+
+impl DateBound {
+    /// Returns `true` if `datetime` satisfies the bound.
+    pub fn contains<Tz: TimeZone>(&self, datetime: &DateTime<Tz>) -> bool {
+        match (self.kind, self.inclusive) {
+            (BoundKind::Upper, true) => *datetime <= self.datetime,
+            (BoundKind::Upper, false) => *datetime < self.datetime,
+            (BoundKind::Lower, true) => *datetime >= self.datetime,
+            (BoundKind::Lower, false) => *datetime > self.datetime,
+        }
+    }
+}
+
+/// The range covered by an approximate expression such as "around 3pm" or "late March".
+///
+/// Like [`DateRange`], the range is half-open: `start` is the first instant inside it
+/// and `end` is the first instant after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Uncertainty {
+    /// First instant of the range, in `ParserConfig::target_zone`.
+    pub start: DateTime<FixedOffset>,
+    /// First instant after the range, in `ParserConfig::target_zone`.
+    pub end: DateTime<FixedOffset>,
+}
+
+/// A recurring date parsed with [`date_parser::recurrence_from_string`], such as "every
+/// other Friday at 9am".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    /// The instant occurrences are counted from: the reference time moved to the weekday
+    /// and the time of day of the expression. It is an occurrence only if it is after
+    /// the reference time.
+    pub start: DateTime<ReferenceZone>,
+    /// The amount and unit between two occurrences, such as `(2, Rule::week_s)`.
+    pub interval: (i32, Rule),
+    /// The number of intervals from `start` to the first occurrence after the reference
+    /// time.
+    pub skipped: u32,
+    /// For recurrences such as "every 2nd Tuesday" or "every last Friday", the position
+    /// of the weekday in each month (`Rule::second` or `Rule::last`) and the weekday.
+    pub weekday_of_month: Option<(Rule, Weekday)>,
+    /// The time zone written after the time of day, as in "every Monday at 9am EST", if
+    /// any.
+    pub source_zone: Option<SourceZone>,
+    /// Whether the expression sets the time of day of the occurrences.
+    pub granularity: Granularity,
+}
+
+impl Recurrence {
+    /// Returns the occurrence `n` intervals after the first one, so `nth(0, ..)` is the
+    /// first occurrence after the reference time. Every occurrence is counted from
+    /// `start`, so "every month" from January 31 gives February 28 and then March 31.
+    pub fn nth(
+        &self,
+        n: u32,
+        config: &ParserConfig,
+    ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
+        let (amount, unit) = self.interval;
+        let amount = n
+            .checked_add(self.skipped)
+            .and_then(|n| i32::try_from(n).ok())
+            .and_then(|n| amount.checked_mul(n))
+            .ok_or_else(|| ParseDateError::ParseError("Too many occurrences".to_string()))?;
+
+        let shifted = date_parser::apply_offsets(self.start, &[(amount, unit)], config)?.datetime;
+        let Some((position, weekday)) = self.weekday_of_month else {
+            return Ok(shifted);
+        };
+
+        let (year, month) = (shifted.year(), shifted.month());
+        let date = match position {
+            Rule::last => date_parser::last_weekday_of_month(year, month, weekday),
+            _ => {
+                let n = date_parser::process_ordinal(position)?;
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+            }
+        }
+        .ok_or_else(|| {
+            ParseDateError::ParseError(format!(
+                "There is no {:?} {:?} in {}-{:02}",
+                position, weekday, year, month
+            ))
+        })?;
+        date_parser::change_date(shifted, date, config.dst_policy).map(|(datetime, _)| datetime)
+    }
+
+    /// Counts `datetime` as the first occurrence if it is one, so that a window starting
+    /// at `datetime` includes it. `datetime` is the reference time the recurrence was
+    /// resolved from, which is otherwise excluded.
+    pub fn include_reference_time(
+        &mut self,
+        datetime: &DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<(), ParseDateError> {
+        if self.skipped > 0 {
+            let previous = Recurrence {
+                skipped: self.skipped - 1,
+                ..self.clone()
+            };
+            if previous.nth(0, config)? == *datetime {
+                *self = previous;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the occurrences after the reference time in order.
+    pub fn occurrences<'a>(
+        &'a self,
+        config: &'a ParserConfig,
+    ) -> impl Iterator<Item = Result<DateTime<ReferenceZone>, ParseDateError>> + 'a {
+        (0..).map(move |n| self.nth(n, config))
+    }
+}
+
+/// A step of resolving an expression, recorded by [`date_parser::trace`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    /// The rule that was resolved, such as "relative_date", or the helper that moved the
+    /// date, such as "shift_to_weekday".
+    pub name: String,
+    /// The text the rule matched, or what the helper was asked to do.
+    pub detail: String,
+    /// The datetime the step produced.
+    pub datetime: DateTime<ReferenceZone>,
+}
+
+/// The result of parsing a date expression with [`date_parser::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedDate {
+    /// The resolved instant, in `ParserConfig::target_zone`.
+    pub datetime: DateTime<FixedOffset>,
+    /// The time zone written in the expression, as in "3pm EST", if any.
+    pub source_zone: Option<SourceZone>,
+    /// How `ParserConfig::dst_policy` resolved the times that occur twice or not at all
+    /// because of daylight saving time, in the order they were met.
+    pub dst_resolutions: Vec<DstResolution>,
+    /// The range an approximate expression such as "around 3pm" or "early next week"
+    /// covers, if the expression is approximate. `datetime` is its central instant.
+    pub uncertainty: Option<Uncertainty>,
+    /// Whether the expression names a time of day or only a day.
+    pub granularity: Granularity,
+}
+
+/// The instant a date value resolves to, along with how `ParserConfig::dst_policy`
+/// resolved the times on the way that occur twice or not at all because of daylight
+/// saving time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Resolved {
+    /// The resolved instant.
+    pub datetime: DateTime<ReferenceZone>,
+    /// The daylight saving time resolutions that were applied, in the order they were
+    /// met, without repeats.
+    pub dst_resolutions: Vec<DstResolution>,
+}
+
+impl Resolved {
+    /// An instant reached without resolving a daylight saving time change.
+    pub fn new(datetime: DateTime<ReferenceZone>) -> Self {
+        Resolved {
+            datetime,
+            dst_resolutions: Vec::new(),
+        }
+    }
+
+    /// Resolves a further step from this instant with `f`, keeping the resolutions
+    /// applied by both.
+    pub fn then(
+        self,
+        f: impl FnOnce(DateTime<ReferenceZone>) -> Result<Resolved, ParseDateError>,
+    ) -> Result<Resolved, ParseDateError> {
+        let next = f(self.datetime)?;
+        let mut dst_resolutions = self.dst_resolutions;
+        for resolution in next.dst_resolutions {
+            if !dst_resolutions.contains(&resolution) {
+                dst_resolutions.push(resolution);
+            }
+        }
+        Ok(Resolved {
+            datetime: next.datetime,
+            dst_resolutions,
+        })
+    }
+}
+
+impl From<(DateTime<ReferenceZone>, Option<DstResolution>)> for Resolved {
+    fn from((datetime, resolution): (DateTime<ReferenceZone>, Option<DstResolution>)) -> Self {
+        Resolved {
+            datetime,
+            dst_resolutions: resolution.into_iter().collect(),
+        }
+    }
+}
+
+/// How precisely a parsed expression names its instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Granularity {
+    /// The expression sets a time of day, as in "tomorrow at 5 PM", "in 2 hours" or
+    /// "end of the month".
+    Time,
+    /// The expression only names a day, as in "next Friday", and keeps the current time
+    /// of day.
+    Day,
+}
+
+/// Options that control how date expressions are resolved.
+///
+/// Use `ParserConfig::default()` and override the fields you need:
+///
+/// ```
+/// use natural_date_parser::{InformalQuantities, ParserConfig};
+///
+/// let config = ParserConfig {
+///     quantities: InformalQuantities {
+///         a_few: 4,
+///         ..Default::default()
+///     },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParserConfig {
+    /// Numeric values used for informal quantities such as "a couple" or "a few".
+    pub quantities: InformalQuantities,
+    /// First day of the week, used by expressions such as "start of next week".
+    /// Defaults to Monday.
+    pub week_start: Weekday,
+    /// Time of day used for the end of a period, as in "end of the month".
+    /// Defaults to 23:59:59.
+    pub end_of_day: NaiveTime,
+    /// Month (1 to 12) in which the fiscal year starts. Quarters ("Q2", "next quarter")
+    /// are counted from this month and fiscal years are named by the calendar year they
+    /// end in, so with April, FY2026 runs from April 2025 to March 2026.
+    /// Defaults to January, which makes fiscal quarters the calendar quarters.
+    pub fiscal_year_start: u32,
+    /// Holidays that can be used as named days, as in "the Friday before Christmas".
+    /// Defaults to [`HolidayCalendar::united_states`].
+    pub holidays: Arc<dyn HolidayProvider>,
+    /// Days that are never business days. Defaults to Saturday and Sunday.
+    pub weekend: Vec<Weekday>,
+    /// Holidays skipped by business-day arithmetic, as in "in 3 business days".
+    /// Defaults to an empty calendar, so only weekends are skipped.
+    pub business_holidays: Arc<dyn HolidayProvider>,
+    /// Named days, times of day and units defined by the application, such as
+    /// "payday", "standup" or "sprint". Defaults to [`Vocabulary::default`].
+    pub vocabulary: Vocabulary,
+    /// Time zone abbreviations accepted after a time, as in "3pm EST".
+    /// Defaults to [`ZoneAbbreviations::default`].
+    pub zone_abbreviations: ZoneAbbreviations,
+    /// Zone expressions are resolved in: "tomorrow at 9" is 9 o'clock in this zone.
+    /// Defaults to `None`, the local time zone.
+    pub zone: Option<Tz>,
+    /// Zone the results of [`date_parser::parse`] are converted to. Defaults to `None`,
+    /// the zone expressions are resolved in.
+    pub target_zone: Option<Zone>,
+    /// How times that occur twice or not at all because of daylight saving time are
+    /// resolved. Defaults to [`DstPolicy::default`].
+    pub dst_policy: DstPolicy,
+    /// How expressions that can be read in more than one way, such as "03/04" or
+    /// "at 7", are resolved. [`date_parser::parse_all`] tries the other readings too.
+    pub readings: Readings,
+    /// How far approximate expressions such as "around 3pm" or "about Friday" reach.
+    /// Defaults to [`Tolerances::default`].
+    pub tolerances: Tolerances,
+    /// Instant relative expressions such as "tomorrow" are resolved from. Defaults to
+    /// `None`, the current time.
+    pub now: Option<DateTime<FixedOffset>>,
+}
+
+impl ParserConfig {
+    /// Returns the zone expressions are resolved in: `zone`, or the local time zone if it
+    /// is not set.
+    pub fn reference_zone(&self) -> ReferenceZone {
+        self.zone
+            .map(ReferenceZone::Named)
+            .unwrap_or(ReferenceZone::Local)
+    }
+
+    /// Returns the instant relative expressions are resolved from: `now` in the
+    /// reference zone, or the current time if it is not set.
+    pub fn reference_time(&self) -> DateTime<ReferenceZone> {
+        let zone = self.reference_zone();
+        self.now
+            .map(|now| now.with_timezone(&zone))
+            .unwrap_or_else(|| zone.now())
+    }
+}
+
+impl Default for ParserConfig {
+    fn default() -> Self {
+        Self {
+            quantities: InformalQuantities::default(),
+            week_start: Weekday::Mon,
+            end_of_day: NaiveTime::from_hms_opt(23, 59, 59).unwrap(),
+            fiscal_year_start: 1,
+            holidays: Arc::new(HolidayCalendar::united_states()),
+            weekend: vec![Weekday::Sat, Weekday::Sun],
+            business_holidays: Arc::new(HolidayCalendar::new()),
+            vocabulary: Vocabulary::default(),
+            zone_abbreviations: ZoneAbbreviations::default(),
+            zone: None,
+            target_zone: None,
+            dst_policy: DstPolicy::default(),
+            readings: Readings::default(),
+            tolerances: Tolerances::default(),
+            now: None,
+        }
+    }
+}
+
+/// Choices for expressions that can be read in more than one way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Readings {
+    /// Order of the month and the day in numeric dates such as "03/04".
+    /// Defaults to month first.
+    pub date_order: DateOrder,
+    /// Which day a weekday on its own, such as "Friday", refers to.
+    /// Defaults to the one in the current week.
+    pub weekday: WeekdayReading,
+    /// Whether an hour without AM or PM, as in "at 7", is in the morning or the
+    /// afternoon. Defaults to [`HourReading::Likely`].
+    pub hour: HourReading,
+}
+
+/// Order of the month and the day in numeric dates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DateOrder {
+    /// "03/04" is March 4, as in the United States.
+    #[default]
+    MonthFirst,
+    /// "03/04" is April 3, as in most of Europe.
+    DayFirst,
+}
+
+/// Which day a weekday on its own refers to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum WeekdayReading {
+    /// The day in the current week, which starts on Sunday; it may be in the past.
+    #[default]
+    SameWeek,
+    /// The first such day from today on.
+    Upcoming,
+    /// The last such day before today.
+    Previous,
+}
+
+/// Whether an hour from 1 to 12 without AM or PM is in the morning or the afternoon.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HourReading {
+    /// 1 to 6 are in the afternoon, 7 to 11 in the morning and 12 is noon.
+    #[default]
+    Likely,
+    /// Always in the morning, with 12 as midnight.
+    Am,
+    /// Always in the afternoon, with 12 as noon.
+    Pm,
+}
+
+/// One of the interpretations returned by [`date_parser::parse_all`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Interpretation {
+    /// The date this interpretation resolves to.
+    pub parsed: ParsedDate,
+    /// How likely this interpretation is, between 0 and 1. The scores of all
+    /// interpretations of an expression add up to 1.
+    pub score: f64,
+    /// The readings that lead to this interpretation.
+    pub readings: Readings,
+}
+
+/// How far approximate expressions reach on either side of the instant they name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tolerances {
+    /// Reach of approximate times, as in "around 3pm". Defaults to 30 minutes.
+    pub time: Duration,
+    /// Reach in days of approximate dates without a time, as in "about Friday".
+    /// Defaults to 1, so "about Friday" covers Thursday to Saturday.
+    pub days: u32,
+}
+
+impl Default for Tolerances {
+    fn default() -> Self {
+        Self {
+            time: Duration::minutes(30),
+            days: 1,
+        }
+    }
+}
+
+/// Numeric values of informal quantities.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InformalQuantities {
+    /// Value of "a couple" and "a couple of". Defaults to 2.
+    pub a_couple: i32,
+    /// Value of "a few". Defaults to 3.
+    pub a_few: i32,
+    /// Value of "several". Defaults to 5.
+    pub several: i32,
+    /// Value of "a dozen" and "a dozen of". Defaults to 12.
+    pub a_dozen: i32,
+}
+
+impl Default for InformalQuantities {
+    fn default() -> Self {
+        Self {
+            a_couple: 2,
+            a_few: 3,
+            several: 5,
+            a_dozen: 12,
+        }
+    }
+}
+
 /// Module for parsing and processing date-related expressions.
 pub mod date_parser {
-    use crate::{DateParser, ParseDateError, Rule};
-    use chrono::{DateTime, Datelike, Duration, Local, TimeZone, Weekday};
+    use crate::zones::{self, DstPolicy, DstResolution, ReferenceZone, SourceZone, Zone};
+    use crate::{
+        BoundKind, DateBound, DateOrder, DateParser, DateRange, Granularity, HourReading,
+        Interpretation, ParseDateError, ParsedDate, ParserConfig, Readings, Recurrence, Resolved,
+        Rule, Step, Uncertainty, WeekdayReading,
+    };
+    use chrono::{
+        DateTime, Datelike, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone,
+        Timelike, Weekday,
+    };
     use chronoutil::delta::shift_months_opt;
-    use pest::iterators::Pair;
     use pest::Parser;
+    use pest::iterators::Pair;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    thread_local! {
+        /// The steps recorded by the innermost [`trace`] running on this thread, if any.
+        static TRACE: RefCell<Option<Vec<Step>>> = const { RefCell::new(None) };
+    }
+
+    /// Runs `f` and returns its result along with the steps of resolving expressions that
+    /// ran inside it on the current thread: the reference time, every date value that was
+    /// resolved, and the helpers that moved dates along the way, in the order they
+    /// finished. Outside of `trace`, nothing is recorded.
+    ///
+    /// ```
+    /// use natural_date_parser::date_parser::{from_string, trace};
+    ///
+    /// let (result, steps) = trace(|| from_string("2 days after tomorrow"));
+    /// assert!(result.is_ok());
+    /// assert!(steps.iter().any(|step| step.name == "relative_term"));
+    /// ```
+    pub fn trace<T>(f: impl FnOnce() -> T) -> (T, Vec<Step>) {
+        let outer = TRACE.with(|trace| trace.replace(Some(Vec::new())));
+        let result = f();
+        let steps = TRACE.with(|trace| trace.replace(outer)).unwrap_or_default();
+        (result, steps)
+    }
+
+    /// Records a step if a [`trace`] is running. `detail` is only built in that case.
+    fn record(name: &str, detail: impl FnOnce() -> String, datetime: &DateTime<ReferenceZone>) {
+        TRACE.with(|trace| {
+            if let Some(steps) = trace.borrow_mut().as_mut() {
+                steps.push(Step {
+                    name: name.to_string(),
+                    detail: detail(),
+                    datetime: *datetime,
+                });
+            }
+        });
+    }
+
+    /// The reference time of `config`, recorded as the first step of a [`trace`].
+    fn reference_time(config: &ParserConfig) -> DateTime<ReferenceZone> {
+        let datetime = config.reference_time();
+        record(
+            "reference_time",
+            || match config.now {
+                Some(_) => "ParserConfig::now".to_string(),
+                None => "the current time".to_string(),
+            },
+            &datetime,
+        );
+        datetime
+    }
 
-    /// Parses a string representing a date and returns the corresponding `DateTime<Local>`.
+    /// Parses a string representing a date and returns the corresponding
+    /// `DateTime<ReferenceZone>`.
     ///
     /// This function takes a date string, parses it using the `pest` parser, and returns the
-    /// resulting `DateTime<Local>` if successful, or an error if the string cannot be parsed.
+    /// resulting `DateTime<ReferenceZone>` if successful, or an error if the string cannot
+    /// be parsed.
     ///
     /// # Arguments
     /// * `string` - The string to be parsed as a date.
     ///
     /// # Returns
-    /// * `Result<DateTime<Local>, ParseDateError>` - A `DateTime<Local>` if parsing is successful,
-    ///   or a `ParseDateError` if there was an issue.
-    pub fn from_string(string: &str) -> Result<DateTime<Local>, ParseDateError> {
+    /// * `Result<DateTime<ReferenceZone>, ParseDateError>` - A `DateTime<ReferenceZone>` if
+    ///   parsing is successful, or a `ParseDateError` if there was an issue.
+    pub fn from_string(string: &str) -> Result<DateTime<ReferenceZone>, ParseDateError> {
+        from_string_with_config(string, &ParserConfig::default())
+    }
+
+    /// Parses a string representing a date like [`from_string`], using the options in `config`.
+    pub fn from_string_with_config(
+        string: &str,
+        config: &ParserConfig,
+    ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
         let pairs = DateParser::parse(Rule::date_expression, string)
             .map_err(|e| ParseDateError::ParseError(e.to_string()))?;
 
         if let Some(pair) = pairs.clone().next() {
             match pair.as_rule() {
                 Rule::date_expression => {
-                    let datetime = process_date_expression(pair)?;
+                    let datetime = process_date_expression(pair, config)?;
                     return Ok(datetime);
                 }
                 _ => {
@@ -50,198 +585,2113 @@ pub mod date_parser {
                 }
             }
         }
-
-        Err(ParseDateError::ParseError(
-            "No valid date expression found".to_string(),
-        ))
+
+        Err(ParseDateError::ParseError(
+            "No valid date expression found".to_string(),
+        ))
+    }
+
+    /// Parses a string representing a date like [`from_string`], returning the instant in
+    /// the reference zone along with the time zone written in the expression.
+    pub fn parse(string: &str) -> Result<ParsedDate, ParseDateError> {
+        parse_with_config(string, &ParserConfig::default())
+    }
+
+    /// Parses a string representing a date like [`parse`], using the options in `config`.
+    ///
+    /// A time written with a zone, as in "3pm PT", is interpreted in that zone on the
+    /// date the rest of the expression resolves to, and the result is converted to
+    /// `config.target_zone`.
+    pub fn parse_with_config(
+        string: &str,
+        config: &ParserConfig,
+    ) -> Result<ParsedDate, ParseDateError> {
+        let pair = parse_date_expression(string)?;
+        process_parsed_date(pair, reference_time(config), config)
+    }
+
+    /// Parses a string representing a date like [`parse`], returning every plausible
+    /// interpretation, the most likely first.
+    pub fn parse_all(string: &str) -> Result<Vec<Interpretation>, ParseDateError> {
+        parse_all_with_config(string, &ParserConfig::default())
+    }
+
+    /// Parses a string representing a date like [`parse_all`], using the options in
+    /// `config`.
+    ///
+    /// Expressions are resolved once for every combination of the readings below that
+    /// applies to them, and readings that resolve to the same instant are merged:
+    ///
+    /// - A numeric date such as "03/04" is read with `config.readings.date_order`
+    ///   (weight 0.7) and with the other order (weight 0.3).
+    /// - A weekday on its own, such as "Friday" or "Friday at 5 PM", is read as the
+    ///   upcoming one (weight 0.6) and as the previous one (weight 0.4).
+    /// - An hour from 1 to 12 without AM or PM, as in "at 7", is read as its
+    ///   [`HourReading::Likely`] reading (weight 0.6, or 0.7 for 1 to 6 and 0.8 for 12)
+    ///   and as the opposite one.
+    ///
+    /// The score of an interpretation is the product of the weights of its readings,
+    /// scaled so that the scores of all interpretations add up to 1. Readings that do not
+    /// resolve, such as "03/25" with the day first, are left out.
+    pub fn parse_all_with_config(
+        string: &str,
+        config: &ParserConfig,
+    ) -> Result<Vec<Interpretation>, ParseDateError> {
+        let pair = parse_date_expression(string)?;
+        let datetime = reference_time(config);
+        let mut interpretations: Vec<Interpretation> = Vec::new();
+        let mut last_error = None;
+
+        for (readings, weight) in reading_candidates(&pair, config) {
+            let config = ParserConfig {
+                readings,
+                ..config.clone()
+            };
+            match process_parsed_date(pair.clone(), datetime, &config) {
+                Ok(parsed) => match interpretations
+                    .iter_mut()
+                    .find(|interpretation| interpretation.parsed.datetime == parsed.datetime)
+                {
+                    Some(interpretation) => interpretation.score += weight,
+                    None => interpretations.push(Interpretation {
+                        parsed,
+                        score: weight,
+                        readings,
+                    }),
+                },
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        let total: f64 = interpretations.iter().map(|i| i.score).sum();
+        if total == 0.0 {
+            return Err(last_error.unwrap_or_else(|| {
+                ParseDateError::ParseError("No interpretation found".to_string())
+            }));
+        }
+        for interpretation in &mut interpretations {
+            interpretation.score /= total;
+        }
+        interpretations.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        Ok(interpretations)
+    }
+
+    /// Lists the readings [`parse_all_with_config`] tries for a `date_expression`, with
+    /// their weights.
+    fn reading_candidates(pair: &Pair<'_, Rule>, config: &ParserConfig) -> Vec<(Readings, f64)> {
+        let base = config.readings;
+        let rules: Vec<_> = pair.clone().into_inner().flatten().collect();
+
+        let date_orders = if rules
+            .iter()
+            .any(|pair| pair.as_rule() == Rule::numeric_date)
+        {
+            let other = match base.date_order {
+                DateOrder::MonthFirst => DateOrder::DayFirst,
+                DateOrder::DayFirst => DateOrder::MonthFirst,
+            };
+            vec![(base.date_order, 0.7), (other, 0.3)]
+        } else {
+            vec![(base.date_order, 1.0)]
+        };
+
+        let bare_weekday = matches!(
+            pair.clone().into_inner().next().map(|pair| pair.as_rule()),
+            Some(Rule::specific_day | Rule::specific_day_and_time)
+        );
+        let weekdays = if bare_weekday {
+            vec![
+                (WeekdayReading::Upcoming, 0.6),
+                (WeekdayReading::Previous, 0.4),
+            ]
+        } else {
+            vec![(base.weekday, 1.0)]
+        };
+
+        let bare_hour = rules
+            .iter()
+            .find(|pair| pair.as_rule() == Rule::bare_hour)
+            .and_then(|pair| pair.as_str().parse::<u32>().ok())
+            .filter(|hour| (1..=12).contains(hour));
+        let hours = match bare_hour {
+            Some(12) => vec![(HourReading::Pm, 0.8), (HourReading::Am, 0.2)],
+            Some(1..=6) => vec![(HourReading::Pm, 0.7), (HourReading::Am, 0.3)],
+            Some(_) => vec![(HourReading::Am, 0.6), (HourReading::Pm, 0.4)],
+            None => vec![(base.hour, 1.0)],
+        };
+
+        let mut candidates = Vec::new();
+        for &(date_order, date_weight) in &date_orders {
+            for &(weekday, weekday_weight) in &weekdays {
+                for &(hour, hour_weight) in &hours {
+                    candidates.push((
+                        Readings {
+                            date_order,
+                            weekday,
+                            hour,
+                        },
+                        date_weight * weekday_weight * hour_weight,
+                    ));
+                }
+            }
+        }
+        candidates
+    }
+
+    /// Parses `string` as a `date_expression`.
+    fn parse_date_expression(string: &str) -> Result<Pair<'_, Rule>, ParseDateError> {
+        DateParser::parse(Rule::date_expression, string)
+            .map_err(|e| ParseDateError::ParseError(e.to_string()))?
+            .next()
+            .ok_or_else(|| ParseDateError::ParseError("No valid date expression found".to_string()))
+    }
+
+    /// Resolves a `date_expression` relative to `datetime` into a [`ParsedDate`].
+    pub fn process_parsed_date(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<ParsedDate, ParseDateError> {
+        let source_zone = match pair
+            .clone()
+            .into_inner()
+            .flatten()
+            .find(|pair| pair.as_rule() == Rule::time_zone)
+        {
+            Some(zone) => Some(process_time_zone(zone, config)?),
+            None => None,
+        };
+        let value = pair
+            .into_inner()
+            .next()
+            .ok_or_else(|| ParseDateError::ParseError("No date expression found".to_string()))?;
+        let (resolved, uncertainty) = match value.as_rule() {
+            Rule::fuzzy_date => {
+                let (range, center) = process_fuzzy_date(value.clone(), datetime, config)?;
+                record(
+                    "fuzzy_date",
+                    || format!("{:?}", value.as_str()),
+                    &center.datetime,
+                );
+                let uncertainty = Uncertainty {
+                    start: to_target_zone(&range.start, config),
+                    end: to_target_zone(&range.end, config),
+                };
+                (center, Some(uncertainty))
+            }
+            _ => (process_date_value(value.clone(), datetime, config)?, None),
+        };
+        let granularity = if has_time_of_day(&value) {
+            Granularity::Time
+        } else {
+            Granularity::Day
+        };
+
+        Ok(ParsedDate {
+            datetime: to_target_zone(&resolved.datetime, config),
+            source_zone,
+            dst_resolutions: resolved.dst_resolutions,
+            uncertainty,
+            granularity,
+        })
+    }
+
+    /// Converts `datetime` to `config.target_zone`, or to a fixed offset in the reference
+    /// zone if it is not set.
+    pub fn to_target_zone(
+        datetime: &DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> DateTime<FixedOffset> {
+        match config.target_zone {
+            Some(zone) => zone.convert(datetime),
+            None => datetime.fixed_offset(),
+        }
+    }
+
+    /// Parses a deadline or a starting point, such as "by Friday", "before tomorrow noon",
+    /// "no later than March 3" or "since Monday", and returns the `DateBound` it sets.
+    pub fn bound_from_string(string: &str) -> Result<DateBound, ParseDateError> {
+        bound_from_string_with_config(string, &ParserConfig::default())
+    }
+
+    /// Parses a deadline or a starting point like [`bound_from_string`], using the options
+    /// in `config`.
+    pub fn bound_from_string_with_config(
+        string: &str,
+        config: &ParserConfig,
+    ) -> Result<DateBound, ParseDateError> {
+        let pair = DateParser::parse(Rule::bound_expression, string)
+            .map_err(|e| ParseDateError::ParseError(e.to_string()))?
+            .next()
+            .ok_or_else(|| {
+                ParseDateError::ParseError("No valid bound expression found".to_string())
+            })?;
+
+        process_bound_expression(pair, reference_time(config), config)
+    }
+
+    /// Resolves a `bound_expression` relative to `datetime`.
+    ///
+    /// "by", "until" and "no later than" set an inclusive deadline and "before" an
+    /// exclusive one; "since", "from" and "no earlier than" set an inclusive starting
+    /// point and "after" an exclusive one. When the expression has no time of day, the
+    /// bound covers whole days: "by Friday" and "after Friday" are at `config.end_of_day`
+    /// on Friday, while "before Friday" and "since Friday" are at its start.
+    ///
+    /// A deadline on a bare weekday is the upcoming one whatever
+    /// `config.readings.weekday` is, so "by Friday" on a Saturday is a week ahead.
+    pub fn process_bound_expression(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<DateBound, ParseDateError> {
+        let mut inner = pair.into_inner();
+        let keyword = inner
+            .next()
+            .and_then(|pair| pair.into_inner().next())
+            .ok_or_else(|| ParseDateError::ParseError("No bound keyword found".to_string()))?;
+        let value = inner
+            .next()
+            .ok_or_else(|| ParseDateError::ParseError("No date expression found".to_string()))?;
+
+        let (kind, inclusive) = match keyword.as_rule() {
+            Rule::by | Rule::until | Rule::no_later_than => (BoundKind::Upper, true),
+            Rule::before => (BoundKind::Upper, false),
+            Rule::since | Rule::no_earlier_than => (BoundKind::Lower, true),
+            Rule::after => (BoundKind::Lower, false),
+            _ => {
+                return Err(ParseDateError::ParseError(format!(
+                    "Invalid bound keyword: {:?}",
+                    keyword.as_rule()
+                )));
+            }
+        };
+        let has_time = has_time_of_day(&value);
+
+        let upcoming;
+        let config = if kind == BoundKind::Upper {
+            upcoming = ParserConfig {
+                readings: Readings {
+                    weekday: WeekdayReading::Upcoming,
+                    ..config.readings
+                },
+                ..config.clone()
+            };
+            &upcoming
+        } else {
+            config
+        };
+
+        let mut resolved = process_date_value(value, datetime, config)?.datetime;
+        if !has_time {
+            let time = match (kind, inclusive) {
+                (BoundKind::Upper, true) | (BoundKind::Lower, false) => config.end_of_day,
+                (BoundKind::Upper, false) | (BoundKind::Lower, true) => NaiveTime::MIN,
+            };
+            resolved = to_local(resolved.date_naive().and_time(time), config)?.0;
+        }
+
+        Ok(DateBound {
+            datetime: to_target_zone(&resolved, config),
+            kind,
+            inclusive,
+        })
+    }
+
+    /// Returns `true` if a date value sets a time of day, as in "tomorrow at noon", "in 2
+    /// hours" or "end of the month", rather than only a day.
+    fn has_time_of_day(pair: &Pair<'_, Rule>) -> bool {
+        std::iter::once(pair.clone())
+            .chain(pair.clone().into_inner().flatten())
+            .any(|pair| {
+                matches!(
+                    pair.as_rule(),
+                    Rule::time_of_day
+                        | Rule::attached_time
+                        | Rule::time_expression
+                        | Rule::period_boundary
+                        | Rule::hour_s
+                        | Rule::minute_s
+                )
+            })
+    }
+
+    /// Resolves a `fuzzy_date` relative to `datetime`, returning the range it covers and
+    /// its central instant.
+    ///
+    /// "around", "about", "roughly" and "approximately" reach `config.tolerances.time`
+    /// on either side of a time ("around 3pm") and `config.tolerances.days` whole days on
+    /// either side of a day ("about Friday"). "early", "mid" and "late" cover the first,
+    /// middle and last third of a period, counted in whole days, or in hours for a single
+    /// day: "early next week" is Monday and Tuesday, "mid March" March 11 to 21 and "late
+    /// tomorrow" tomorrow from 16:00. The central instant is then the middle of the range.
+    pub fn process_fuzzy_date(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<(DateRange, Resolved), ParseDateError> {
+        let mut inner = pair.into_inner();
+        let qualifier = inner
+            .next()
+            .ok_or_else(|| ParseDateError::ParseError("No qualifier found".to_string()))?;
+        let value = inner
+            .next()
+            .ok_or_else(|| ParseDateError::ParseError("No date expression found".to_string()))?;
+
+        if qualifier.as_rule() == Rule::approximately {
+            let has_time = has_time_of_day(&value);
+            let center = process_date_value(value, datetime, config)?;
+            let range = if has_time {
+                let reach = config.tolerances.time;
+                DateRange {
+                    start: center.datetime - reach,
+                    end: center.datetime + reach,
+                }
+            } else {
+                let reach = Duration::days(config.tolerances.days as i64);
+                let day = center.datetime.date_naive();
+                date_range(day - reach, day + reach, config)?
+            };
+            return Ok((range, center));
+        }
+
+        let portion = qualifier
+            .into_inner()
+            .next()
+            .map(|pair| pair.as_rule())
+            .ok_or_else(|| ParseDateError::ParseError("No period portion found".to_string()))?;
+        let (first, last) = process_period(value, datetime, config)?;
+        let range = date_range(first, last, config)?;
+        let days = (last - first).num_days() + 1;
+
+        let (start, end) = if days == 1 {
+            let third = (range.end - range.start) / 3;
+            match portion {
+                Rule::early => (range.start, range.start + third),
+                Rule::mid => (range.start + third, range.end - third),
+                _ => (range.end - third, range.end),
+            }
+        } else {
+            let first_cut = first + Duration::days((days as f64 / 3.0).round() as i64);
+            let second_cut = first + Duration::days((days as f64 * 2.0 / 3.0).round() as i64);
+            let at_midnight = |date: NaiveDate| {
+                to_local(date.and_time(NaiveTime::MIN), config).map(|(datetime, _)| datetime)
+            };
+            match portion {
+                Rule::early => (range.start, at_midnight(first_cut)?),
+                Rule::mid => (at_midnight(first_cut)?, at_midnight(second_cut)?),
+                _ => (at_midnight(second_cut)?, range.end),
+            }
+        };
+
+        let center = Resolved::new(start + (end - start) / 2);
+        Ok((DateRange { start, end }, center))
+    }
+
+    /// Parses a string representing a period, such as "Q2", "FY2026" or "next fiscal
+    /// quarter", and returns the `DateRange` it covers.
+    pub fn range_from_string(string: &str) -> Result<DateRange, ParseDateError> {
+        range_from_string_with_config(string, &ParserConfig::default())
+    }
+
+    /// Parses a string representing a period like [`range_from_string`], using the options
+    /// in `config`.
+    pub fn range_from_string_with_config(
+        string: &str,
+        config: &ParserConfig,
+    ) -> Result<DateRange, ParseDateError> {
+        let pair = DateParser::parse(Rule::range_expression, string)
+            .map_err(|e| ParseDateError::ParseError(e.to_string()))?
+            .next()
+            .ok_or_else(|| {
+                ParseDateError::ParseError("No valid range expression found".to_string())
+            })?;
+
+        process_range_expression(pair, reference_time(config), config)
+    }
+
+    /// Parses a string representing a duration, such as "2 hours and 30 minutes" or
+    /// "a couple of weeks", and returns its amount/unit pairs like [`process_duration`].
+    pub fn duration_from_string(string: &str) -> Result<Vec<(i32, Rule)>, ParseDateError> {
+        duration_from_string_with_config(string, &ParserConfig::default())
+    }
+
+    /// Parses a string representing a duration like [`duration_from_string`], using the
+    /// options in `config`.
+    pub fn duration_from_string_with_config(
+        string: &str,
+        config: &ParserConfig,
+    ) -> Result<Vec<(i32, Rule)>, ParseDateError> {
+        let pair = DateParser::parse(Rule::duration_expression, string)
+            .map_err(|e| ParseDateError::ParseError(e.to_string()))?
+            .next()
+            .ok_or_else(|| ParseDateError::ParseError("No valid duration found".to_string()))?;
+
+        for inner_pair in pair.into_inner() {
+            if inner_pair.as_rule() == Rule::duration {
+                return process_duration(inner_pair, config);
+            }
+        }
+
+        Err(ParseDateError::ParseError("No duration found".to_string()))
+    }
+
+    /// Parses a string representing a recurring date, such as "every day", "every 2
+    /// weeks" or "every other Friday at 9am", and returns a [`Recurrence`] whose
+    /// occurrences start after the current time.
+    pub fn recurrence_from_string(string: &str) -> Result<Recurrence, ParseDateError> {
+        recurrence_from_string_with_config(string, &ParserConfig::default())
+    }
+
+    /// Parses a string representing a recurring date like [`recurrence_from_string`],
+    /// using the options in `config`.
+    pub fn recurrence_from_string_with_config(
+        string: &str,
+        config: &ParserConfig,
+    ) -> Result<Recurrence, ParseDateError> {
+        let pair = DateParser::parse(Rule::recurrence_expression, string)
+            .map_err(|e| ParseDateError::ParseError(e.to_string()))?
+            .next()
+            .ok_or_else(|| {
+                ParseDateError::ParseError("No valid recurrence expression found".to_string())
+            })?;
+
+        process_recurrence_expression(pair, reference_time(config), config)
+    }
+
+    /// Resolves a `recurrence_expression` relative to `datetime`.
+    ///
+    /// Occurrences keep the time of day of `datetime` unless a time is given, and the
+    /// first one is the earliest occurrence after `datetime`: "every day" starts
+    /// tomorrow at the current time and "every Monday at 9am" on the next Monday at
+    /// 9:00, which is today if it is Monday before 9:00.
+    pub fn process_recurrence_expression(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Recurrence, ParseDateError> {
+        let mut interval: Option<(i32, Rule)> = None;
+        let mut weekday: Option<Weekday> = None;
+        let mut weekday_of_month: Option<(Rule, Weekday)> = None;
+        let mut source_zone = None;
+        let mut granularity = Granularity::Day;
+        let mut start = datetime;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::every | Rule::EOI => {}
+                Rule::recurrence_interval => {
+                    let (step, day, position) = process_recurrence_interval(inner_pair, config)?;
+                    interval = Some(step);
+                    match (day, position) {
+                        (Some(day), Some(position)) => weekday_of_month = Some((position, day)),
+                        (day, _) => weekday = day,
+                    }
+                }
+                Rule::time_of_day => {
+                    if let Some(zone) = inner_pair
+                        .clone()
+                        .into_inner()
+                        .find(|pair| pair.as_rule() == Rule::time_zone)
+                    {
+                        source_zone = Some(process_time_zone(zone, config)?);
+                    }
+                    granularity = Granularity::Time;
+                    start = process_time_of_day(inner_pair, start, config)?.datetime;
+                }
+                _ => {
+                    return Err(ParseDateError::ParseError(
+                        "Unexpected rule in recurrence".to_string(),
+                    ));
+                }
+            }
+        }
+
+        let interval = interval
+            .ok_or_else(|| ParseDateError::ParseError("Interval not provided".to_string()))?;
+        if interval.0 <= 0 {
+            return Err(ParseDateError::ParseError(
+                "The interval of a recurrence must be positive".to_string(),
+            ));
+        }
+
+        if let Some(weekday) = weekday {
+            let days_ahead =
+                (weekday.num_days_from_monday() + 7 - start.weekday().num_days_from_monday()) % 7;
+            start = change_date(
+                start,
+                start.date_naive() + Duration::days(days_ahead as i64),
+                config.dst_policy,
+            )?
+            .0;
+        }
+
+        let mut recurrence = Recurrence {
+            start,
+            interval,
+            skipped: 0,
+            weekday_of_month,
+            source_zone,
+            granularity,
+        };
+        while recurrence.nth(0, config)? <= datetime {
+            recurrence.skipped += 1;
+        }
+
+        Ok(recurrence)
+    }
+
+    /// The amount and unit between occurrences, the weekday they fall on and its position
+    /// in the month.
+    type RecurrenceInterval = ((i32, Rule), Option<Weekday>, Option<Rule>);
+
+    /// Parses a `recurrence_interval`, returning the amount and unit between occurrences,
+    /// the weekday they fall on, if one is given, and its position in the month for
+    /// recurrences such as "every 2nd Tuesday".
+    ///
+    /// A fifth weekday is rejected, since most months do not have one.
+    pub fn process_recurrence_interval(
+        pair: Pair<'_, Rule>,
+        config: &ParserConfig,
+    ) -> Result<RecurrenceInterval, ParseDateError> {
+        let mut amount = 1;
+        let mut size: Option<i32> = None;
+        let mut unit: Option<Rule> = None;
+        let mut weekday: Option<Weekday> = None;
+        let mut position: Option<Rule> = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::other => amount = 2,
+                Rule::ordinal => {
+                    let ordinal = inner_pair.into_inner().next().map(|pair| pair.as_rule());
+                    if ordinal == Some(Rule::fifth) {
+                        return Err(ParseDateError::ParseError(
+                            "Recurrences on the fifth weekday of a month are not supported, \
+                             since most months do not have one"
+                                .to_string(),
+                        ));
+                    }
+                    position = ordinal;
+                }
+                Rule::month_s => {}
+                Rule::number => amount = process_number(inner_pair, config)?,
+                Rule::time_unit => {
+                    (size, unit) = process_time_unit(inner_pair, config)
+                        .map(|(size, unit)| (size, Some(unit)))?
+                }
+                Rule::specific_day => {
+                    let day = inner_pair.into_inner().next().ok_or_else(|| {
+                        ParseDateError::ParseError("Weekday not provided".to_string())
+                    })?;
+                    weekday = Some(process_weekday(day.as_rule())?);
+                    unit = Some(match position {
+                        Some(_) => Rule::month_s,
+                        None => Rule::week_s,
+                    });
+                }
+                _ => {
+                    return Err(ParseDateError::ParseError(
+                        "Unexpected rule in recurrence interval".to_string(),
+                    ));
+                }
+            }
+        }
+
+        let unit =
+            unit.ok_or_else(|| ParseDateError::ParseError("Time unit not provided".to_string()))?;
+        let amount = amount
+            .checked_mul(size.unwrap_or(1))
+            .ok_or_else(|| ParseDateError::ParseError("Invalid recurrence interval".to_string()))?;
+
+        Ok(((amount, unit), weekday, position))
+    }
+
+    /// Resolves a `range_expression` relative to `datetime`.
+    pub fn process_range_expression(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<DateRange, ParseDateError> {
+        for inner_pair in pair.into_inner() {
+            if inner_pair.as_rule() == Rule::period {
+                let (start, end) = process_period(inner_pair, datetime, config)?;
+                return date_range(start, end, config);
+            }
+        }
+
+        Err(ParseDateError::ParseError(
+            "No range expression found".to_string(),
+        ))
+    }
+
+    /// Builds the `DateRange` covering the days from `start` to `end`, both inclusive.
+    pub fn date_range(
+        start: NaiveDate,
+        end: NaiveDate,
+        config: &ParserConfig,
+    ) -> Result<DateRange, ParseDateError> {
+        let next_day = end
+            .succ_opt()
+            .ok_or_else(|| ParseDateError::ParseError("Invalid range end".to_string()))?;
+
+        Ok(DateRange {
+            start: to_local(start.and_time(NaiveTime::MIN), config)?.0,
+            end: to_local(next_day.and_time(NaiveTime::MIN), config)?.0,
+        })
+    }
+
+    pub fn process_date_expression(
+        pair: Pair<'_, Rule>,
+        config: &ParserConfig,
+    ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
+        let datetime = reference_time(config);
+        if let Some(inner_pair) = pair.into_inner().next() {
+            return process_date_value(inner_pair, datetime, config)
+                .map(|resolved| resolved.datetime);
+        }
+
+        Err(ParseDateError::ParseError(
+            "No date expression found".to_string(),
+        ))
+    }
+
+    /// Resolves one of the expressions a `date_expression` can contain relative to
+    /// `datetime`.
+    pub fn process_date_value(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let (rule, text) = (pair.as_rule(), pair.as_str());
+        let result = match rule {
+            Rule::anchored_date => process_anchored_date(pair, datetime, config),
+            Rule::fuzzy_date => {
+                process_fuzzy_date(pair, datetime, config).map(|(_, center)| center)
+            }
+            Rule::relative_date => process_relative_date_at(pair, datetime).map(Resolved::new),
+            Rule::business_day_anchor => process_business_day_anchor(pair, datetime, config),
+            Rule::weekday_of_month => process_weekday_of_month(pair, datetime, config),
+            Rule::weekday_in_week => process_weekday_in_week(pair, datetime, config),
+            Rule::calendar_date => process_calendar_date(pair, datetime, config),
+            Rule::numeric_date => process_numeric_date(pair, datetime, config),
+            Rule::iso_week_date => {
+                let (day, last_day) = process_iso_week_date(pair)?;
+                if day != last_day {
+                    return Err(ParseDateError::ParseError(
+                        "ISO week date has no day of the week".to_string(),
+                    ));
+                }
+                change_date(datetime, day, config.dst_policy).map(Resolved::from)
+            }
+            Rule::period_boundary => process_period_boundary(pair, datetime, config),
+            Rule::relative_term => process_relative_term(pair, datetime).map(Resolved::new),
+            Rule::time_expression => process_time_of_day(pair, datetime, config),
+            Rule::specific_day => {
+                process_weekday_reading(pair, datetime, config).map(Resolved::new)
+            }
+            Rule::specific_day_and_time => process_specific_day_and_time(pair, datetime, config),
+            Rule::relative_day_and_specific_time => {
+                process_relative_day_and_specific_time(pair, datetime, config)
+            }
+            Rule::future_time => process_future_time(pair, datetime, config),
+            Rule::holiday => process_holiday(pair, datetime, config),
+            Rule::past_time => process_past_time(pair, datetime, config),
+            _ => Err(ParseDateError::ParseError(
+                "Unexpected rule encountered".to_string(),
+            )),
+        };
+
+        if let Ok(resolved) = &result {
+            record(
+                &format!("{:?}", rule),
+                || format!("{:?}", text),
+                &resolved.datetime,
+            );
+        }
+        result
+    }
+
+    /// Resolves a date relative to another date expression, such as "2 days after next
+    /// Monday" or "the Friday before Christmas".
+    ///
+    /// The inner expression is resolved first. A duration is then added to or subtracted
+    /// from it like in [`process_future_time`], and a weekday moves to the nearest such
+    /// weekday strictly before or after it, so "the Friday after" a Friday is a week
+    /// later. "the day before" and "the day after" move by one day.
+    pub fn process_anchored_date(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let mut inner_pairs = pair.into_inner();
+        let (Some(shift), Some(direction), Some(anchor)) =
+            (inner_pairs.next(), inner_pairs.next(), inner_pairs.next())
+        else {
+            return Err(ParseDateError::ParseError(
+                "Incomplete anchored date expression".to_string(),
+            ));
+        };
+
+        let is_before =
+            direction.into_inner().next().map(|pair| pair.as_rule()) == Some(Rule::before);
+        let anchor = process_date_value(anchor, datetime, config)?;
+        let sign = if is_before { -1 } else { 1 };
+
+        let shift = shift
+            .into_inner()
+            .next()
+            .ok_or_else(|| ParseDateError::ParseError("Shift not provided".to_string()))?;
+
+        match shift.as_rule() {
+            Rule::duration => {
+                let offsets: Vec<_> = process_duration(shift, config)?
+                    .into_iter()
+                    .map(|(amount, unit)| (sign * amount, unit))
+                    .collect();
+                anchor.then(|anchor| apply_offsets(anchor, &offsets, config))
+            }
+            Rule::specific_day => {
+                let weekday = match shift.into_inner().next() {
+                    Some(day) => process_weekday(day.as_rule())?,
+                    None => {
+                        return Err(ParseDateError::ParseError(
+                            "Weekday not provided".to_string(),
+                        ));
+                    }
+                };
+                // The first such weekday within the week that starts the day after the
+                // anchor, or that ends the day before it.
+                anchor.then(|anchor| {
+                    let start = if is_before {
+                        anchor - Duration::days(7)
+                    } else {
+                        anchor + Duration::days(1)
+                    };
+                    shift_to_weekday(start, weekday, Rule::this).map(Resolved::new)
+                })
+            }
+            Rule::day_s => {
+                anchor.then(|anchor| apply_offsets(anchor, &[(sign, Rule::day_s)], config))
+            }
+            _ => Err(ParseDateError::ParseError(format!(
+                "Unexpected rule in anchored date: {:?}",
+                shift.as_rule()
+            ))),
+        }
+    }
+
+    /// Shifts `datetime` forward by a compound duration such as "2 weeks and 3 days".
+    ///
+    /// Amounts given for the same unit are added together and then applied from the
+    /// largest calendar unit down: years and months are applied first as a single month
+    /// shift (clamping to the last day of the month, so January 31 plus one month is the
+    /// last day of February), followed by weeks, days, hours and minutes.
+    pub fn process_future_time(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        match pair.into_inner().next() {
+            Some(inner_pair) if inner_pair.as_rule() == Rule::duration => {
+                let offsets = process_duration(inner_pair, config)?;
+                apply_offsets(datetime, &offsets, config)
+            }
+            Some(_) => Err(ParseDateError::ParseError("Unexpected rule".to_string())),
+            None => Err(ParseDateError::ParseError(
+                "Duration not provided".to_string(),
+            )),
+        }
+    }
+
+    /// Shifts `datetime` backward by a compound duration such as "twenty-five minutes ago",
+    /// applying the units in the same order as [`process_future_time`].
+    pub fn process_past_time(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        match pair.into_inner().next() {
+            Some(inner_pair) if inner_pair.as_rule() == Rule::duration => {
+                let offsets: Vec<_> = process_duration(inner_pair, config)?
+                    .into_iter()
+                    .map(|(amount, unit)| (-amount, unit))
+                    .collect();
+                apply_offsets(datetime, &offsets, config)
+            }
+            Some(_) => Err(ParseDateError::ParseError("Unexpected rule".to_string())),
+            None => Err(ParseDateError::ParseError(
+                "Duration not provided".to_string(),
+            )),
+        }
+    }
+
+    /// Collects the amount/unit pairs of a `duration` in the order they were written.
+    pub fn process_duration(
+        pair: Pair<'_, Rule>,
+        config: &ParserConfig,
+    ) -> Result<Vec<(i32, Rule)>, ParseDateError> {
+        pair.into_inner()
+            .map(|offset| process_offset(offset, config))
+            .collect()
+    }
+
+    /// Parses a single `offset`, returning its amount and the matched unit rule
+    /// (for example `Rule::day_s`).
+    ///
+    /// "half a" / "half an" is expressed in the next smaller unit, so "half an hour" is
+    /// returned as 30 minutes and "half a year" as 6 months.
+    pub fn process_offset(
+        pair: Pair<'_, Rule>,
+        config: &ParserConfig,
+    ) -> Result<(i32, Rule), ParseDateError> {
+        let mut amount = 0;
+        let mut is_half = false;
+        let mut multiplier: Option<i32> = None;
+        let mut unit: Option<Rule> = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::number => {
+                    if is_half_quantity(&inner_pair) {
+                        is_half = true;
+                    } else {
+                        amount = process_number(inner_pair, config)?;
+                    }
+                }
+                Rule::time_unit => {
+                    let (size, base_unit) = process_time_unit(inner_pair, config)?;
+                    multiplier = size;
+                    unit = Some(base_unit);
+                }
+                _ => {
+                    return Err(ParseDateError::ParseError("Unexpected rule".to_string()));
+                }
+            }
+        }
+
+        if let Some(multiplier) = multiplier {
+            if is_half {
+                return Err(ParseDateError::ParseError(
+                    "Half is not supported for named units".to_string(),
+                ));
+            }
+            amount = amount
+                .checked_mul(multiplier)
+                .ok_or_else(|| ParseDateError::ParseError("Invalid duration value".to_string()))?;
+        }
+
+        match (unit, is_half) {
+            (Some(unit), false) => Ok((amount, unit)),
+            (Some(Rule::year_s), true) => Ok((6, Rule::month_s)),
+            (Some(Rule::week_s), true) => Ok((84, Rule::hour_s)),
+            (Some(Rule::day_s), true) => Ok((12, Rule::hour_s)),
+            (Some(Rule::hour_s), true) => Ok((30, Rule::minute_s)),
+            (Some(unit), true) => Err(ParseDateError::ParseError(format!(
+                "Half is not supported for {:?}",
+                unit
+            ))),
+            (None, _) => Err(ParseDateError::ParseError(
+                "Time unit not provided".to_string(),
+            )),
+        }
+    }
+
+    /// Parses a `time_unit`, returning the unit rule (for example `Rule::day_s`). A unit
+    /// from `config.vocabulary` such as "fortnight" is returned as its base unit along
+    /// with the number of base units it stands for.
+    pub fn process_time_unit(
+        pair: Pair<'_, Rule>,
+        config: &ParserConfig,
+    ) -> Result<(Option<i32>, Rule), ParseDateError> {
+        match pair.into_inner().next() {
+            Some(named) if named.as_rule() == Rule::named_unit => {
+                let (size, unit) = config.vocabulary.unit(named.as_str()).ok_or_else(|| {
+                    ParseDateError::ParseError(format!("Unknown time unit: {:?}", named.as_str()))
+                })?;
+                Ok((Some(size), unit))
+            }
+            Some(unit) => Ok((None, unit.as_rule())),
+            None => Err(ParseDateError::ParseError(
+                "Time unit not provided".to_string(),
+            )),
+        }
+    }
+
+    /// Resolves "next business day" or "previous business day", keeping the time of day of
+    /// `datetime`.
+    pub fn process_business_day_anchor(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let amount = match pair.into_inner().next().map(|pair| pair.as_rule()) {
+            Some(Rule::next) => 1,
+            Some(Rule::previous) => -1,
+            _ => {
+                return Err(ParseDateError::ParseError(
+                    "Expected next or previous business day".to_string(),
+                ));
+            }
+        };
+
+        let date = shift_business_days(datetime.date_naive(), amount, config)?;
+        change_date(datetime, date, config.dst_policy).map(Resolved::from)
+    }
+
+    /// Returns `true` if `date` is neither a weekend day nor a business holiday in `config`.
+    pub fn is_business_day(date: NaiveDate, config: &ParserConfig) -> bool {
+        !config.weekend.contains(&date.weekday()) && !config.business_holidays.is_holiday(date)
+    }
+
+    /// Moves `date` by `amount` business days, counting only days for which
+    /// [`is_business_day`] is `true`. Negative amounts move backwards.
+    ///
+    /// Whole weeks are skipped arithmetically and holidays are looked up once per year
+    /// with [`HolidayProvider::holidays_in`], so large amounts take no longer than small
+    /// ones.
+    pub fn shift_business_days(
+        date: NaiveDate,
+        amount: i64,
+        config: &ParserConfig,
+    ) -> Result<NaiveDate, ParseDateError> {
+        let mut calendar = BusinessCalendar::new(config)?;
+
+        // Moves past `remaining` working weekdays, then past as many more as there were
+        // holidays among them, until a stretch without holidays is crossed.
+        let mut date = date;
+        let mut remaining = amount.abs();
+        while remaining > 0 {
+            let next = calendar.shift_weekdays(date, remaining * amount.signum())?;
+            remaining = calendar.holidays_between(date, next);
+            date = next;
+        }
+
+        Ok(date)
+    }
+
+    /// Counts the business days after `start` up to and including `end`, so that
+    /// `shift_business_days(start, n)` is `n` business days from `start`. If `end` is
+    /// before `start`, the days after `end` up to `start` are counted, negated. Fails if
+    /// every day of the week is a weekend day.
+    pub fn business_days_between(
+        start: NaiveDate,
+        end: NaiveDate,
+        config: &ParserConfig,
+    ) -> Result<i64, ParseDateError> {
+        let (first, last, sign) = if start <= end {
+            (start, end, 1)
+        } else {
+            (end, start, -1)
+        };
+        let mut calendar = BusinessCalendar::new(config)?;
+        Ok(
+            sign * (calendar.weekdays_between(first, last)
+                - calendar.holidays_between(first, last)),
+        )
+    }
+
+    /// The working weekdays and holidays of a [`ParserConfig`], for business-day
+    /// arithmetic over long spans.
+    struct BusinessCalendar<'a> {
+        config: &'a ParserConfig,
+        /// The number of working weekdays in a week.
+        per_week: i64,
+        /// Holidays on working weekdays, sorted, by year.
+        holidays: HashMap<i32, Vec<NaiveDate>>,
+    }
+
+    impl<'a> BusinessCalendar<'a> {
+        fn new(config: &'a ParserConfig) -> Result<Self, ParseDateError> {
+            let per_week = [
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+                Weekday::Sat,
+                Weekday::Sun,
+            ]
+            .iter()
+            .filter(|day| !config.weekend.contains(day))
+            .count() as i64;
+            if per_week == 0 {
+                return Err(ParseDateError::ParseError(
+                    "Every day of the week is a weekend day".to_string(),
+                ));
+            }
+
+            Ok(BusinessCalendar {
+                config,
+                per_week,
+                holidays: HashMap::new(),
+            })
+        }
+
+        fn is_working_weekday(&self, date: NaiveDate) -> bool {
+            !self.config.weekend.contains(&date.weekday())
+        }
+
+        /// Moves `date` by `amount` working weekdays, ignoring holidays.
+        fn shift_weekdays(
+            &self,
+            date: NaiveDate,
+            amount: i64,
+        ) -> Result<NaiveDate, ParseDateError> {
+            let invalid = || ParseDateError::ParseError("Invalid day adjustment".to_string());
+
+            // Any 7 consecutive days hold `per_week` working weekdays, so whole weeks are
+            // skipped at once, leaving between 1 and `per_week` days to step through.
+            let weeks = (amount.abs() - 1) / self.per_week;
+            let mut remaining = amount.abs() - weeks * self.per_week;
+            let step = Duration::days(amount.signum());
+            let mut date = Duration::try_weeks(weeks * amount.signum())
+                .and_then(|skip| date.checked_add_signed(skip))
+                .ok_or_else(invalid)?;
+
+            while remaining > 0 {
+                date = date.checked_add_signed(step).ok_or_else(invalid)?;
+                if self.is_working_weekday(date) {
+                    remaining -= 1;
+                }
+            }
+            Ok(date)
+        }
+
+        /// Counts the working weekdays after `first` up to and including `last`.
+        fn weekdays_between(&self, first: NaiveDate, last: NaiveDate) -> i64 {
+            let days = (last - first).num_days();
+            let weeks = days / 7;
+            let rest = (0..days % 7)
+                .filter(|offset| self.is_working_weekday(last - Duration::days(*offset)))
+                .count() as i64;
+            weeks * self.per_week + rest
+        }
+
+        /// Counts the holidays on working weekdays strictly between `from` and `to`, or
+        /// equal to `to`, in either direction.
+        fn holidays_between(&mut self, from: NaiveDate, to: NaiveDate) -> i64 {
+            let (first, last) = if from <= to { (from, to) } else { (to, from) };
+            let mut count = 0;
+            for year in first.year()..=last.year() {
+                count += self
+                    .holidays_in(year)
+                    .iter()
+                    .filter(|date| **date != from && (first..=last).contains(*date))
+                    .count() as i64;
+            }
+            count
+        }
+
+        fn holidays_in(&mut self, year: i32) -> &[NaiveDate] {
+            let config = self.config;
+            self.holidays.entry(year).or_insert_with(|| {
+                let mut dates: Vec<NaiveDate> = config
+                    .business_holidays
+                    .holidays_in(year)
+                    .into_iter()
+                    .map(|(_, date)| date)
+                    .filter(|date| !config.weekend.contains(&date.weekday()))
+                    .collect();
+                dates.sort();
+                dates.dedup();
+                dates
+            })
+        }
+    }
+
+    /// Returns the value of a `number`, whether it is written with digits, as words or as
+    /// an informal quantity. Informal quantities take their values from `config`.
+    pub fn process_number(
+        pair: Pair<'_, Rule>,
+        config: &ParserConfig,
+    ) -> Result<i32, ParseDateError> {
+        let inner_pair = pair
+            .into_inner()
+            .next()
+            .ok_or_else(|| ParseDateError::ParseError("Number not provided".to_string()))?;
+
+        match inner_pair.as_rule() {
+            Rule::numeral => inner_pair
+                .as_str()
+                .parse::<i32>()
+                .map_err(|_| ParseDateError::ParseError("Invalid duration value".to_string())),
+            Rule::cardinal => cardinal_value(inner_pair.as_str()).ok_or_else(|| {
+                ParseDateError::ParseError(format!("Invalid number: {:?}", inner_pair.as_str()))
+            }),
+            Rule::article => Ok(1),
+            Rule::quantity => {
+                let quantities = &config.quantities;
+                match inner_pair.into_inner().next().map(|pair| pair.as_rule()) {
+                    Some(Rule::a_couple) => Ok(quantities.a_couple),
+                    Some(Rule::a_few) => Ok(quantities.a_few),
+                    Some(Rule::several) => Ok(quantities.several),
+                    Some(Rule::a_dozen) => Ok(quantities.a_dozen),
+                    _ => Err(ParseDateError::ParseError(
+                        "Unexpected quantity".to_string(),
+                    )),
+                }
+            }
+            _ => Err(ParseDateError::ParseError(
+                "Unexpected rule in number".to_string(),
+            )),
+        }
+    }
+
+    fn is_half_quantity(pair: &Pair<'_, Rule>) -> bool {
+        pair.clone()
+            .into_inner()
+            .flat_map(|pair| pair.into_inner())
+            .any(|pair| pair.as_rule() == Rule::half)
+    }
+
+    /// Converts a cardinal written as words, such as "twenty-five" or "a hundred and one",
+    /// into its value.
+    pub fn cardinal_value(words: &str) -> Option<i32> {
+        let mut value = 0;
+
+        for word in words
+            .split(|c: char| c == '-' || c.is_whitespace())
+            .filter(|word| !word.is_empty())
+        {
+            let word = word.to_lowercase();
+            value = match word.as_str() {
+                "and" => value,
+                "a" => 1,
+                "hundred" => value.max(1) * 100,
+                _ => value + word_value(&word)?,
+            };
+        }
+
+        Some(value)
+    }
+
+    fn word_value(word: &str) -> Option<i32> {
+        const WORDS: [&str; 20] = [
+            "zero",
+            "one",
+            "two",
+            "three",
+            "four",
+            "five",
+            "six",
+            "seven",
+            "eight",
+            "nine",
+            "ten",
+            "eleven",
+            "twelve",
+            "thirteen",
+            "fourteen",
+            "fifteen",
+            "sixteen",
+            "seventeen",
+            "eighteen",
+            "nineteen",
+        ];
+        const TENS: [&str; 8] = [
+            "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+        ];
+
+        if let Some(value) = WORDS.iter().position(|w| *w == word) {
+            return Some(value as i32);
+        }
+        TENS.iter()
+            .position(|w| *w == word)
+            .map(|index| (index as i32 + 2) * 10)
+    }
+
+    /// Applies amount/unit pairs to `datetime` in the order documented on
+    /// [`process_future_time`]. Business days are applied after days and skip the
+    /// weekend and business holidays set in `config`. Negative amounts shift backwards.
+    pub fn apply_offsets(
+        datetime: DateTime<ReferenceZone>,
+        offsets: &[(i32, Rule)],
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let mut months: i32 = 0;
+        let mut weeks: i64 = 0;
+        let mut days: i64 = 0;
+        let mut business_days: i64 = 0;
+        let mut hours: i64 = 0;
+        let mut minutes: i64 = 0;
+
+        for &(amount, unit) in offsets {
+            match unit {
+                Rule::year_s => {
+                    months = amount
+                        .checked_mul(12)
+                        .and_then(|amount| months.checked_add(amount))
+                        .ok_or_else(|| {
+                            ParseDateError::ParseError("Invalid year adjustment".to_string())
+                        })?;
+                }
+                Rule::month_s => {
+                    months = months.checked_add(amount).ok_or_else(|| {
+                        ParseDateError::ParseError("Invalid month adjustment".to_string())
+                    })?;
+                }
+                Rule::week_s => weeks += amount as i64,
+                Rule::day_s => days += amount as i64,
+                Rule::business_day_s => business_days += amount as i64,
+                Rule::hour_s => hours += amount as i64,
+                Rule::minute_s => minutes += amount as i64,
+                _ => {
+                    return Err(ParseDateError::ParseError("Invalid time unit".to_string()));
+                }
+            }
+        }
+
+        let mut datetime = datetime;
+        let mut dst_resolutions = Vec::new();
+        if months != 0 {
+            let date = shift_months_opt(datetime.date_naive(), months).ok_or_else(|| {
+                ParseDateError::ParseError("Invalid month adjustment".to_string())
+            })?;
+            let resolution;
+            (datetime, resolution) = change_date(datetime, date, config.dst_policy)?;
+            dst_resolutions.extend(resolution);
+        }
+
+        let (weeks, days) = Duration::try_weeks(weeks)
+            .zip(Duration::try_days(days))
+            .ok_or_else(|| ParseDateError::ParseError("Invalid duration value".to_string()))?;
+        datetime = datetime
+            .checked_add_signed(weeks)
+            .and_then(|datetime| datetime.checked_add_signed(days))
+            .ok_or_else(|| ParseDateError::ParseError("Invalid time adjustment".to_string()))?;
+
+        if business_days != 0 {
+            let date = shift_business_days(datetime.date_naive(), business_days, config)?;
+            let resolution;
+            (datetime, resolution) = change_date(datetime, date, config.dst_policy)?;
+            if let Some(resolution) = resolution
+                && !dst_resolutions.contains(&resolution)
+            {
+                dst_resolutions.push(resolution);
+            }
+        }
+
+        let (hours, minutes) = Duration::try_hours(hours)
+            .zip(Duration::try_minutes(minutes))
+            .ok_or_else(|| ParseDateError::ParseError("Invalid duration value".to_string()))?;
+        let shifted = datetime
+            .checked_add_signed(hours)
+            .and_then(|datetime| datetime.checked_add_signed(minutes))
+            .ok_or_else(|| ParseDateError::ParseError("Invalid time adjustment".to_string()))?;
+        record(
+            "apply_offsets",
+            || {
+                let offsets: Vec<String> = offsets
+                    .iter()
+                    .map(|(amount, unit)| format!("{:+} {:?}", amount, unit))
+                    .collect();
+                offsets.join(", ")
+            },
+            &shifted,
+        );
+
+        Ok(Resolved {
+            datetime: shifted,
+            dst_resolutions,
+        })
+    }
+
+    pub fn process_specific_day_and_time(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let mut resolved = Resolved::new(datetime);
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::specific_day => {
+                    resolved.datetime =
+                        process_weekday_reading(inner_pair, resolved.datetime, config)?;
+                }
+                Rule::time_of_day => {
+                    resolved = process_time_of_day(inner_pair, resolved.datetime, config)?;
+                }
+                _ => {
+                    return Err(ParseDateError::ParseError(format!(
+                        "Unexpected rule in specific date and time: {:?}",
+                        inner_pair.as_rule()
+                    )));
+                }
+            }
+        }
+        Ok(resolved)
+    }
+
+    /// Resolves a day such as "tomorrow" or "next Friday" followed or preceded by a time
+    /// of day. Setting the time of day replaces the time the day was resolved at, along
+    /// with how daylight saving time affected it.
+    pub fn process_relative_day_and_specific_time(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let mut resolved = Resolved::new(datetime);
+        for inner_pair in pair.into_inner() {
+            resolved = match inner_pair.as_rule() {
+                Rule::time_of_day | Rule::attached_time => {
+                    process_time_of_day(inner_pair, resolved.datetime, config)?
+                }
+                _ => resolved.then(|datetime| process_date_value(inner_pair, datetime, config))?,
+            };
+        }
+        Ok(resolved)
+    }
+
+    pub fn process_relative_date(
+        pair: Pair<'_, Rule>,
+    ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
+        process_relative_date_at(pair, ReferenceZone::Local.now())
+    }
+
+    /// Resolves a `relative_date` such as "next Tuesday" like [`process_relative_date`],
+    /// relative to `datetime` instead of the current time.
+    pub fn process_relative_date_at(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+    ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
+        let inner_pairs: Vec<_> = pair.clone().into_inner().collect();
+
+        if inner_pairs.len() == 2 {
+            let first_pair = &inner_pairs[0];
+            let second_pair = &inner_pairs[1];
+
+            if first_pair.as_rule() == Rule::next_or_last
+                && second_pair.as_rule() == Rule::specific_day
+            {
+                let direction = first_pair.clone().into_inner().last().unwrap().as_rule();
+
+                if let Some(inner_pair) = second_pair.clone().into_inner().next() {
+                    match process_weekday(inner_pair.as_rule()) {
+                        Ok(target_weekday) => {
+                            return shift_to_weekday(datetime, target_weekday, direction);
+                        }
+                        Err(e) => {
+                            return Err(ParseDateError::ParseError(format!(
+                                "Unrecognized relative date: {:?}",
+                                e.to_string()
+                            )));
+                        }
+                    }
+                }
+
+                Err(ParseDateError::ParseError(format!(
+                    "Unrecognized relative date: {:?}",
+                    second_pair.to_string()
+                )))
+            } else {
+                Err(ParseDateError::ParseError(
+                    "Pair did not match expected structure for relative_date.".to_string(),
+                ))
+            }
+        } else {
+            Err(ParseDateError::ParseError(
+                "Unexpected number of inner pairs in relative_date.".to_string(),
+            ))
+        }
+    }
+
+    /// Resolves expressions such as "the second Tuesday of March" or "last Friday of the
+    /// month", keeping the time of day of `datetime`.
+    ///
+    /// A month given by name refers to that month of the current year unless a year is
+    /// written after it. Returns an error when the month has no such occurrence, for
+    /// example a fifth Monday in February 2025.
+    pub fn process_weekday_of_month(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let mut position: Option<Rule> = None;
+        let mut weekday: Option<Weekday> = None;
+        let mut month: Option<(i32, u32)> = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::ordinal => {
+                    position = inner_pair.into_inner().next().map(|pair| pair.as_rule());
+                }
+                Rule::specific_day => {
+                    if let Some(day) = inner_pair.into_inner().next() {
+                        weekday = Some(process_weekday(day.as_rule())?);
+                    }
+                }
+                Rule::month_reference => {
+                    month = Some(process_month_reference(inner_pair, datetime)?);
+                }
+                _ => {
+                    return Err(ParseDateError::ParseError(format!(
+                        "Unexpected rule in weekday of month: {:?}",
+                        inner_pair.as_rule()
+                    )));
+                }
+            }
+        }
+
+        let (Some(position), Some(weekday), Some((year, month))) = (position, weekday, month)
+        else {
+            return Err(ParseDateError::ParseError(
+                "Incomplete weekday of month expression".to_string(),
+            ));
+        };
+
+        let date = match position {
+            Rule::last => last_weekday_of_month(year, month, weekday),
+            _ => {
+                let n = process_ordinal(position)?;
+                NaiveDate::from_weekday_of_month_opt(year, month, weekday, n)
+            }
+        }
+        .ok_or_else(|| {
+            ParseDateError::ParseError(format!(
+                "There is no {:?} {:?} in {}-{:02}",
+                position, weekday, year, month
+            ))
+        })?;
+
+        change_date(datetime, date, config.dst_policy).map(Resolved::from)
+    }
+
+    /// Resolves the start, middle or end of a period, such as "end of the month" or
+    /// "start of next week".
+    ///
+    /// Starts are at midnight, ends are at `config.end_of_day` on the last day of the
+    /// period, and middles are halfway between the start of the period and the start of
+    /// the following one (noon for a day, Thursday noon for a week starting on Monday).
+    /// Weeks begin on `config.week_start`.
+    pub fn process_period_boundary(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let mut boundary = Rule::period_end;
+        let mut bounds: Option<(NaiveDate, NaiveDate)> = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::period_abbreviation => {
+                    let unit = match inner_pair.into_inner().next().map(|pair| pair.as_rule()) {
+                        Some(Rule::eod) => Rule::day_s,
+                        Some(Rule::eow) => Rule::week_s,
+                        Some(Rule::eom) => Rule::month_s,
+                        Some(Rule::eoq) => Rule::quarter_s,
+                        Some(Rule::eoy) => Rule::year_s,
+                        _ => {
+                            return Err(ParseDateError::ParseError(
+                                "Unexpected period abbreviation".to_string(),
+                            ));
+                        }
+                    };
+                    bounds = Some(period_bounds(unit, datetime.date_naive(), 0, config)?);
+                }
+                Rule::boundary => {
+                    if let Some(inner) = inner_pair.into_inner().next() {
+                        boundary = inner.as_rule();
+                    }
+                }
+                Rule::period => {
+                    bounds = Some(process_period(inner_pair, datetime, config)?);
+                }
+                _ => {
+                    return Err(ParseDateError::ParseError(format!(
+                        "Unexpected rule in period boundary: {:?}",
+                        inner_pair.as_rule()
+                    )));
+                }
+            }
+        }
+
+        let (start, end) =
+            bounds.ok_or_else(|| ParseDateError::ParseError("Period not provided".to_string()))?;
+
+        let naive = match boundary {
+            Rule::period_start => start.and_time(NaiveTime::MIN),
+            Rule::period_end => end.and_time(config.end_of_day),
+            Rule::period_middle => {
+                let start = start.and_time(NaiveTime::MIN);
+                let next_start = (end + Duration::days(1)).and_time(NaiveTime::MIN);
+                start + (next_start - start) / 2
+            }
+            _ => {
+                return Err(ParseDateError::ParseError(format!(
+                    "Unexpected boundary: {:?}",
+                    boundary
+                )));
+            }
+        };
+
+        to_local(naive, config).map(Resolved::from)
+    }
+
+    /// Resolves a `period` to the first and last date it contains.
+    ///
+    /// Quarters and fiscal years follow `config.fiscal_year_start`. A quarter without a
+    /// year, such as "Q3", belongs to the current fiscal year.
+    pub fn process_period(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<(NaiveDate, NaiveDate), ParseDateError> {
+        let today = datetime.date_naive();
+        let mut shift = 0;
+        let mut quarter: Option<u32> = None;
+        let mut fiscal_year: Option<i32> = None;
+        let mut month: Option<u32> = None;
+        let mut year: Option<i32> = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::next_or_last => {
+                    shift = match inner_pair.into_inner().next().map(|pair| pair.as_rule()) {
+                        Some(Rule::next) => 1,
+                        Some(Rule::last) => -1,
+                        _ => 0,
+                    };
+                }
+                Rule::period_unit => {
+                    let unit = match inner_pair.into_inner().next().map(|pair| pair.as_rule()) {
+                        Some(Rule::fiscal_quarter) => Rule::quarter_s,
+                        Some(unit) => unit,
+                        None => {
+                            return Err(ParseDateError::ParseError(
+                                "Period unit not provided".to_string(),
+                            ));
+                        }
+                    };
+                    return period_bounds(unit, today, shift, config);
+                }
+                Rule::week_number => {
+                    return process_week_number(inner_pair, datetime);
+                }
+                Rule::iso_week_date => {
+                    return process_iso_week_date(inner_pair);
+                }
+                Rule::quarter_number => {
+                    quarter = Some(inner_pair.as_str()[1..].parse::<u32>().map_err(|e| {
+                        ParseDateError::ParseError(format!("Failed to parse quarter: {}", e))
+                    })?);
+                }
+                Rule::fiscal_year_number => {
+                    fiscal_year = Some(process_fiscal_year_number(inner_pair)?);
+                }
+                Rule::month_name => {
+                    if let Some(name) = inner_pair.into_inner().next() {
+                        month = Some(process_month(name.as_rule())?);
+                    }
+                }
+                Rule::year_number => {
+                    year = Some(inner_pair.as_str().parse::<i32>().map_err(|e| {
+                        ParseDateError::ParseError(format!("Failed to parse year: {}", e))
+                    })?);
+                }
+                Rule::relative_term => {
+                    let day = process_relative_term(inner_pair, datetime)?.date_naive();
+                    return Ok((day, day));
+                }
+                Rule::holiday => {
+                    let day = process_holiday(inner_pair, datetime, config)?
+                        .datetime
+                        .date_naive();
+                    return Ok((day, day));
+                }
+                _ => {
+                    return Err(ParseDateError::ParseError(format!(
+                        "Unexpected rule in period: {:?}",
+                        inner_pair.as_rule()
+                    )));
+                }
+            }
+        }
+
+        let invalid = || ParseDateError::ParseError("Invalid period".to_string());
+
+        if let Some(quarter) = quarter {
+            let fiscal_year = match fiscal_year.or(year) {
+                Some(fiscal_year) => fiscal_year,
+                None => fiscal_year_of(today, config)?,
+            };
+            let start = shift_months_opt(
+                fiscal_year_start_date(fiscal_year, config)?,
+                (quarter as i32 - 1) * 3,
+            )
+            .ok_or_else(invalid)?;
+            return period_bounds(Rule::quarter_s, start, 0, config);
+        }
+
+        if let Some(fiscal_year) = fiscal_year {
+            let start = fiscal_year_start_date(fiscal_year, config)?;
+            return period_bounds(Rule::fiscal_year, start, 0, config);
+        }
+
+        let (unit, month) = match month {
+            Some(month) => (Rule::month_s, month),
+            None => (Rule::year_s, 1),
+        };
+        let date =
+            NaiveDate::from_ymd_opt(year.unwrap_or(today.year()), month, 1).ok_or_else(invalid)?;
+        period_bounds(unit, date, 0, config)
+    }
+
+    /// Resolves a named day such as "Christmas", "next Easter", "Thanksgiving 2026" or
+    /// "payday", keeping the time of day of `datetime`.
+    ///
+    /// The name is looked up first among the anchors and day parts of `config.vocabulary`
+    /// and then in `config.holidays`. A day part on its own, such as "noon", means today
+    /// at that time. For anchors and holidays, "next" picks the first occurrence after
+    /// today and "last" the most recent one before it. Without a direction or a year, a
+    /// holiday resolves to the current year and an anchor to its first occurrence from
+    /// today on.
+    pub fn process_holiday(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let today = datetime.date_naive();
+        let mut direction = Rule::this;
+        let mut name = "";
+        let mut year = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::next_or_last => {
+                    if let Some(inner) = inner_pair.into_inner().next() {
+                        direction = inner.as_rule();
+                    }
+                }
+                Rule::named_day => name = inner_pair.as_str(),
+                Rule::year_number => {
+                    year = Some(inner_pair.as_str().parse::<i32>().map_err(|e| {
+                        ParseDateError::ParseError(format!("Failed to parse year: {}", e))
+                    })?);
+                }
+                _ => {
+                    return Err(ParseDateError::ParseError(format!(
+                        "Unexpected rule in holiday: {:?}",
+                        inner_pair.as_rule()
+                    )));
+                }
+            }
+        }
+
+        if let Some(is_occurrence) = config.vocabulary.anchor(name) {
+            let date =
+                find_anchor(today, direction, year, is_occurrence.as_ref()).ok_or_else(|| {
+                    ParseDateError::ParseError(format!("No occurrence of {:?} found", name))
+                })?;
+            return change_date(datetime, date, config.dst_policy).map(Resolved::from);
+        }
+
+        if let Some(time) = config.vocabulary.day_part(name) {
+            if direction != Rule::this || year.is_some() {
+                return Err(ParseDateError::ParseError(format!(
+                    "{:?} is a time of day, not a day",
+                    name
+                )));
+            }
+            return to_local(today.and_time(time), config).map(Resolved::from);
+        }
+
+        let date_in = |year: i32| {
+            config
+                .holidays
+                .date_of(name, year)
+                .ok_or_else(|| ParseDateError::ParseError(format!("Unknown day: {:?}", name)))
+        };
+
+        let year = year.unwrap_or(today.year());
+        let mut date = date_in(year)?;
+        match direction {
+            Rule::next if date <= today => date = date_in(year + 1)?,
+            Rule::last if date >= today => date = date_in(year - 1)?,
+            _ => {}
+        }
+
+        change_date(datetime, date, config.dst_policy).map(Resolved::from)
+    }
+
+    /// Finds the occurrence of an anchor as described on [`process_holiday`], searching at
+    /// most four years from the starting point. When `year` is given, the first occurrence
+    /// in that year is returned.
+    pub fn find_anchor(
+        today: NaiveDate,
+        direction: Rule,
+        year: Option<i32>,
+        is_occurrence: &(dyn Fn(NaiveDate) -> bool + Send + Sync),
+    ) -> Option<NaiveDate> {
+        const SEARCH_DAYS: usize = 366 * 4;
+
+        let (start, step) = match (direction, year) {
+            (_, Some(year)) => (NaiveDate::from_ymd_opt(year, 1, 1)?, 1),
+            (Rule::next, None) => (today.succ_opt()?, 1),
+            (Rule::last, None) => (today.pred_opt()?, -1),
+            _ => (today, 1),
+        };
+
+        let mut date = start;
+        for _ in 0..SEARCH_DAYS {
+            if is_occurrence(date) {
+                return Some(date);
+            }
+            date = date.checked_add_signed(Duration::days(step))?;
+        }
+        None
     }
 
-    pub fn process_date_expression(
+    /// Resolves expressions such as "next week's Monday" or "Friday of week 42", keeping
+    /// the time of day of `datetime`.
+    ///
+    /// Weeks given by number are ISO weeks and start on Monday; relative weeks such as
+    /// "next week" start on `config.week_start`.
+    pub fn process_weekday_in_week(
         pair: Pair<'_, Rule>,
-    ) -> Result<DateTime<Local>, ParseDateError> {
-        let datetime = Local::now();
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let mut weekday: Option<Weekday> = None;
+        let mut week_start: Option<NaiveDate> = None;
+
         for inner_pair in pair.into_inner() {
             match inner_pair.as_rule() {
-                Rule::relative_date => {
-                    let parsed = process_relative_date(inner_pair)?;
-                    return Ok(parsed);
-                }
-                Rule::relative_term => {
-                    let parsed = process_relative_term(inner_pair)?;
-                    return Ok(parsed);
-                }
-                Rule::specific_time => {
-                    let parsed = process_specific_time(inner_pair, datetime)?;
-                    return Ok(parsed);
-                }
                 Rule::specific_day => {
-                    if let Some(inner) = inner_pair.into_inner().next() {
-                        let parsed = process_specific_day(inner.as_rule(), datetime)?;
-                        return Ok(parsed);
+                    if let Some(day) = inner_pair.into_inner().next() {
+                        weekday = Some(process_weekday(day.as_rule())?);
                     }
                 }
-                Rule::specific_day_and_time => {
-                    let parsed = process_specific_day_and_time(inner_pair)?;
-                    return Ok(parsed);
-                }
-                Rule::relative_day_and_specific_time => {
-                    let parsed = process_relative_day_and_specific_time(inner_pair)?;
-                    return Ok(parsed);
-                }
-                Rule::future_time => {
-                    let parsed = process_future_time(inner_pair)?;
-                    return Ok(parsed);
+                Rule::week_reference => {
+                    week_start = Some(process_week_reference(inner_pair, datetime, config)?.0);
                 }
                 _ => {
-                    return Err(ParseDateError::ParseError(
-                        "Unexpected rule encountered".to_string(),
-                    ));
+                    return Err(ParseDateError::ParseError(format!(
+                        "Unexpected rule in weekday of week: {:?}",
+                        inner_pair.as_rule()
+                    )));
                 }
             }
         }
 
-        Err(ParseDateError::ParseError(
-            "No date expression found".to_string(),
-        ))
+        let (Some(weekday), Some(week_start)) = (weekday, week_start) else {
+            return Err(ParseDateError::ParseError(
+                "Incomplete weekday of week expression".to_string(),
+            ));
+        };
+
+        let days_from_start =
+            (7 + weekday.num_days_from_monday() - week_start.weekday().num_days_from_monday()) % 7;
+        change_date(
+            datetime,
+            week_start + Duration::days(days_from_start as i64),
+            config.dst_policy,
+        )
+        .map(Resolved::from)
     }
 
-    pub fn process_future_time(pair: Pair<'_, Rule>) -> Result<DateTime<Local>, ParseDateError> {
-        let mut datetime = Local::now();
-        let mut duration = 0;
-        let mut unit: Option<Rule> = None;
+    /// Resolves a `week_reference` to the first and last date of the week.
+    pub fn process_week_reference(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<(NaiveDate, NaiveDate), ParseDateError> {
+        let mut shift = 0;
 
         for inner_pair in pair.into_inner() {
             match inner_pair.as_rule() {
-                Rule::number => {
-                    duration = inner_pair.as_str().trim().parse::<i32>().map_err(|_| {
-                        ParseDateError::ParseError("Invalid duration value".to_string())
-                    })?;
-                }
-                Rule::time_unit => {
-                    unit = Some(inner_pair.as_rule());
+                Rule::week_number => return process_week_number(inner_pair, datetime),
+                Rule::next_or_last => {
+                    shift = match inner_pair.into_inner().next().map(|pair| pair.as_rule()) {
+                        Some(Rule::next) => 1,
+                        Some(Rule::last) => -1,
+                        _ => 0,
+                    };
                 }
+                Rule::week_s => {}
                 _ => {
-                    return Err(ParseDateError::ParseError("Unexpected rule".to_string()));
+                    return Err(ParseDateError::ParseError(format!(
+                        "Unexpected rule in week reference: {:?}",
+                        inner_pair.as_rule()
+                    )));
                 }
             }
         }
 
-        if let Some(unit) = unit {
-            datetime = match unit {
-                Rule::day_s => datetime + Duration::days(duration as i64),
-                Rule::week_s => datetime + Duration::weeks(duration as i64),
-                Rule::month_s => shift_months_opt(datetime, duration).ok_or_else(|| {
-                    ParseDateError::ParseError("Invalid month adjustment".to_string())
-                })?,
-                Rule::year_s => {
-                    datetime
-                        .with_year(datetime.year() + duration)
-                        .ok_or_else(|| {
-                            ParseDateError::ParseError("Invalid year adjustment".to_string())
-                        })?
-                }
-                _ => {
-                    return Err(ParseDateError::ParseError("Invalid time unit".to_string()));
-                }
-            };
-            Ok(datetime)
-        } else {
-            Err(ParseDateError::ParseError(
-                "Time unit not provided".to_string(),
-            ))
-        }
+        period_bounds(Rule::week_s, datetime.date_naive(), shift, config)
     }
 
-    pub fn process_specific_day_and_time(
+    /// Resolves an ISO week number such as "week 42" or "week 7 of 2025" to the Monday and
+    /// Sunday of that week. Without a year, the ISO year of `datetime` is used.
+    pub fn process_week_number(
         pair: Pair<'_, Rule>,
-    ) -> Result<DateTime<Local>, ParseDateError> {
-        let mut datetime = Local::now();
+        datetime: DateTime<ReferenceZone>,
+    ) -> Result<(NaiveDate, NaiveDate), ParseDateError> {
+        let mut year = datetime.iso_week().year();
+        let mut week = 0;
+
         for inner_pair in pair.into_inner() {
             match inner_pair.as_rule() {
-                Rule::specific_day => {
-                    datetime = process_specific_day(inner_pair.as_rule(), datetime)?;
+                Rule::week_of_year => {
+                    week = inner_pair.as_str().parse::<u32>().map_err(|e| {
+                        ParseDateError::ParseError(format!("Failed to parse week: {}", e))
+                    })?;
                 }
-                Rule::specific_time => {
-                    datetime = process_specific_time(inner_pair, datetime)?;
+                Rule::year_number => {
+                    year = inner_pair.as_str().parse::<i32>().map_err(|e| {
+                        ParseDateError::ParseError(format!("Failed to parse year: {}", e))
+                    })?;
                 }
                 _ => {
                     return Err(ParseDateError::ParseError(format!(
-                        "Unexpected rule in specific date and time: {:?}",
+                        "Unexpected rule in week number: {:?}",
                         inner_pair.as_rule()
                     )));
                 }
             }
         }
-        Ok(datetime)
+
+        iso_week_bounds(year, week)
     }
 
-    pub fn process_relative_day_and_specific_time(
+    /// Resolves an ISO 8601 week date. "2025-W07-3" gives that single day, while
+    /// "2025-W07" gives the Monday and Sunday of the week.
+    pub fn process_iso_week_date(
         pair: Pair<'_, Rule>,
-    ) -> Result<DateTime<Local>, ParseDateError> {
-        let mut datetime = Local::now();
-        for inner_pair in pair.into_inner() {
-            match inner_pair.as_rule() {
-                Rule::relative_date => {
-                    datetime = process_relative_date(inner_pair)?;
-                }
-                Rule::relative_term => {
-                    datetime = process_relative_term(inner_pair)?;
-                }
-                Rule::specific_time => {
-                    datetime = process_specific_time(inner_pair, datetime)?;
-                }
-                _ => {}
+    ) -> Result<(NaiveDate, NaiveDate), ParseDateError> {
+        let digits: String = pair
+            .as_str()
+            .chars()
+            .filter(|c| c.is_ascii_digit())
+            .collect();
+        let invalid =
+            || ParseDateError::ParseError(format!("Invalid ISO week date: {:?}", pair.as_str()));
+
+        let year = digits[..4].parse::<i32>().map_err(|_| invalid())?;
+        let week = digits[4..6].parse::<u32>().map_err(|_| invalid())?;
+        let (monday, sunday) = iso_week_bounds(year, week)?;
+
+        match digits.get(6..7) {
+            Some(day) => {
+                let day = day.parse::<i64>().map_err(|_| invalid())?;
+                let date = monday + Duration::days(day - 1);
+                Ok((date, date))
             }
+            None => Ok((monday, sunday)),
         }
-        Ok(datetime)
     }
 
-    pub fn process_relative_date(pair: Pair<'_, Rule>) -> Result<DateTime<Local>, ParseDateError> {
-        let datetime = Local::now();
-        let inner_pairs: Vec<_> = pair.clone().into_inner().collect();
+    /// Returns the Monday and Sunday of an ISO week, or an error if the year has no such
+    /// week.
+    pub fn iso_week_bounds(year: i32, week: u32) -> Result<(NaiveDate, NaiveDate), ParseDateError> {
+        let monday = NaiveDate::from_isoywd_opt(year, week, Weekday::Mon).ok_or_else(|| {
+            ParseDateError::ParseError(format!("There is no week {} in {}", week, year))
+        })?;
 
-        if inner_pairs.len() == 2 {
-            let first_pair = &inner_pairs[0];
-            let second_pair = &inner_pairs[1];
+        Ok((monday, monday + Duration::days(6)))
+    }
 
-            if first_pair.as_rule() == Rule::next_or_last
-                && second_pair.as_rule() == Rule::specific_day
-            {
-                let direction = first_pair.clone().into_inner().last().unwrap().as_rule();
+    /// Parses a `fiscal_year_number` such as "FY2026" or "FY26" into its calendar year.
+    pub fn process_fiscal_year_number(pair: Pair<'_, Rule>) -> Result<i32, ParseDateError> {
+        let digits = pair.as_str()[2..].trim();
+        let year = digits.parse::<i32>().map_err(|e| {
+            ParseDateError::ParseError(format!("Failed to parse fiscal year: {}", e))
+        })?;
 
-                if let Some(inner_pair) = second_pair.clone().into_inner().next() {
-                    match process_weekday(inner_pair.as_rule()) {
-                        Ok(target_weekday) => {
-                            return shift_to_weekday(datetime, target_weekday, direction);
-                        }
-                        Err(e) => {
-                            return Err(ParseDateError::ParseError(format!(
-                                "Unrecognized relative date: {:?}",
-                                e.to_string()
-                            )));
-                        }
+        Ok(if digits.len() == 2 { 2000 + year } else { year })
+    }
+
+    /// Returns the fiscal year that contains `date`, named by the calendar year it ends in.
+    pub fn fiscal_year_of(date: NaiveDate, config: &ParserConfig) -> Result<i32, ParseDateError> {
+        let start_month = fiscal_year_start_month(config)?;
+
+        if start_month > 1 && date.month() >= start_month {
+            Ok(date.year() + 1)
+        } else {
+            Ok(date.year())
+        }
+    }
+
+    /// Returns the first day of the given fiscal year.
+    pub fn fiscal_year_start_date(
+        fiscal_year: i32,
+        config: &ParserConfig,
+    ) -> Result<NaiveDate, ParseDateError> {
+        let start_month = fiscal_year_start_month(config)?;
+        let year = if start_month == 1 {
+            fiscal_year
+        } else {
+            fiscal_year - 1
+        };
+
+        NaiveDate::from_ymd_opt(year, start_month, 1)
+            .ok_or_else(|| ParseDateError::ParseError("Invalid fiscal year".to_string()))
+    }
+
+    fn fiscal_year_start_month(config: &ParserConfig) -> Result<u32, ParseDateError> {
+        if (1..=12).contains(&config.fiscal_year_start) {
+            Ok(config.fiscal_year_start)
+        } else {
+            Err(ParseDateError::ParseError(format!(
+                "Invalid fiscal year start month: {}",
+                config.fiscal_year_start
+            )))
+        }
+    }
+
+    /// Returns the first and last date of the day, week, month, quarter, fiscal year or
+    /// year that contains `date`, moved by `shift` periods.
+    pub fn period_bounds(
+        unit: Rule,
+        date: NaiveDate,
+        shift: i32,
+        config: &ParserConfig,
+    ) -> Result<(NaiveDate, NaiveDate), ParseDateError> {
+        let invalid = || ParseDateError::ParseError("Invalid period adjustment".to_string());
+
+        let (start, months) = match unit {
+            Rule::day_s => {
+                let day = date
+                    .checked_add_signed(Duration::days(shift as i64))
+                    .ok_or_else(invalid)?;
+                return Ok((day, day));
+            }
+            Rule::week_s => {
+                let days_since_start = (7 + date.weekday().num_days_from_monday()
+                    - config.week_start.num_days_from_monday())
+                    % 7;
+                let start = date
+                    .checked_sub_signed(Duration::days(days_since_start as i64))
+                    .and_then(|start| start.checked_add_signed(Duration::weeks(shift as i64)))
+                    .ok_or_else(invalid)?;
+                return Ok((start, start + Duration::days(6)));
+            }
+            Rule::month_s => (date.with_day(1).ok_or_else(invalid)?, 1),
+            Rule::quarter_s | Rule::fiscal_year => {
+                let months_into_fiscal_year =
+                    (date.month0() + 13 - fiscal_year_start_month(config)?) % 12;
+                let months = if unit == Rule::quarter_s { 3 } else { 12 };
+                let first_day = date.with_day(1).ok_or_else(invalid)?;
+                (
+                    shift_months_opt(first_day, -(months_into_fiscal_year as i32 % months))
+                        .ok_or_else(invalid)?,
+                    months,
+                )
+            }
+            Rule::year_s => (
+                NaiveDate::from_ymd_opt(date.year(), 1, 1).ok_or_else(invalid)?,
+                12,
+            ),
+            _ => {
+                return Err(ParseDateError::ParseError(format!(
+                    "Invalid period unit: {:?}",
+                    unit
+                )));
+            }
+        };
+
+        let start = shift_months_opt(start, shift * months).ok_or_else(invalid)?;
+        let end = shift_months_opt(start, months)
+            .and_then(|next_start| next_start.pred_opt())
+            .ok_or_else(invalid)?;
+        Ok((start, end))
+    }
+
+    /// Maps an ordinal rule (`Rule::first` to `Rule::fifth`) to its position.
+    pub fn process_ordinal(position: Rule) -> Result<u8, ParseDateError> {
+        match position {
+            Rule::first => Ok(1),
+            Rule::second => Ok(2),
+            Rule::third => Ok(3),
+            Rule::fourth => Ok(4),
+            Rule::fifth => Ok(5),
+            _ => Err(ParseDateError::ParseError(format!(
+                "Invalid ordinal: {:?}",
+                position
+            ))),
+        }
+    }
+
+    /// Resolves a `month_reference` to a year and month number relative to `datetime`.
+    pub fn process_month_reference(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+    ) -> Result<(i32, u32), ParseDateError> {
+        let mut year = datetime.year();
+        let mut month = datetime.month();
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::month_name => {
+                    if let Some(name) = inner_pair.into_inner().next() {
+                        month = process_month(name.as_rule())?;
                     }
                 }
-
-                Err(ParseDateError::ParseError(format!(
-                    "Unrecognized relative date: {:?}",
-                    second_pair.to_string()
-                )))
-            } else {
-                Err(ParseDateError::ParseError(
-                    "Pair did not match expected structure for relative_date.".to_string(),
-                ))
+                Rule::year_number => {
+                    year = inner_pair.as_str().parse::<i32>().map_err(|e| {
+                        ParseDateError::ParseError(format!("Failed to parse year: {}", e))
+                    })?;
+                }
+                Rule::next_or_last => {
+                    let shift = match inner_pair.into_inner().next().map(|pair| pair.as_rule()) {
+                        Some(Rule::next) => 1,
+                        Some(Rule::last) => -1,
+                        _ => 0,
+                    };
+                    let shifted =
+                        shift_months_opt(datetime.date_naive(), shift).ok_or_else(|| {
+                            ParseDateError::ParseError("Invalid month adjustment".to_string())
+                        })?;
+                    year = shifted.year();
+                    month = shifted.month();
+                }
+                Rule::month_s => {}
+                _ => {
+                    return Err(ParseDateError::ParseError(format!(
+                        "Unexpected rule in month reference: {:?}",
+                        inner_pair.as_rule()
+                    )));
+                }
             }
-        } else {
-            Err(ParseDateError::ParseError(
-                "Unexpected number of inner pairs in relative_date.".to_string(),
-            ))
+        }
+
+        Ok((year, month))
+    }
+
+    pub fn process_month(month: Rule) -> Result<u32, ParseDateError> {
+        match month {
+            Rule::january => Ok(1),
+            Rule::february => Ok(2),
+            Rule::march => Ok(3),
+            Rule::april => Ok(4),
+            Rule::may => Ok(5),
+            Rule::june => Ok(6),
+            Rule::july => Ok(7),
+            Rule::august => Ok(8),
+            Rule::september => Ok(9),
+            Rule::october => Ok(10),
+            Rule::november => Ok(11),
+            Rule::december => Ok(12),
+            _ => Err(ParseDateError::ParseError(format!(
+                "Invalid month: {:?}",
+                month
+            ))),
         }
     }
 
-    pub fn process_relative_term(pair: Pair<'_, Rule>) -> Result<DateTime<Local>, ParseDateError> {
-        let datetime = Local::now();
+    /// Returns the date of the last `weekday` in the given month.
+    pub fn last_weekday_of_month(year: i32, month: u32, weekday: Weekday) -> Option<NaiveDate> {
+        let last_day = shift_months_opt(NaiveDate::from_ymd_opt(year, month, 1)?, 1)?.pred_opt()?;
+        let days_back =
+            (7 + last_day.weekday().num_days_from_monday() - weekday.num_days_from_monday()) % 7;
+        last_day.checked_sub_signed(Duration::days(days_back as i64))
+    }
 
+    pub fn process_relative_term(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+    ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
         if let Some(inner_pair) = pair.clone().into_inner().next() {
             match inner_pair.as_rule() {
                 Rule::tomorrow => {
@@ -269,11 +2719,21 @@ pub mod date_parser {
 
     pub fn process_specific_time(
         pair: Pair<'_, Rule>,
-        datetime: DateTime<Local>,
-    ) -> Result<DateTime<Local>, ParseDateError> {
+        datetime: DateTime<ReferenceZone>,
+    ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
+        let (hour, minute) = process_clock_time(pair)?;
+
+        let modified_datetime = change_time(datetime, hour, minute)?;
+
+        Ok(modified_datetime)
+    }
+
+    /// Returns the hour (0 to 23) and minute of a `specific_time`. Times without AM or PM
+    /// are read on a 24-hour clock.
+    pub fn process_clock_time(pair: Pair<'_, Rule>) -> Result<(u32, u32), ParseDateError> {
         let mut hour: u32 = 0;
         let mut minute: u32 = 0;
-        let mut is_pm = false;
+        let mut is_pm: Option<bool> = None;
 
         // Iterate through inner pairs to capture hour, minute, and am_pm
         for inner_pair in pair.into_inner() {
@@ -296,9 +2756,7 @@ pub mod date_parser {
                     })?;
                 }
                 Rule::am_pm => {
-                    if let Some(res) = process_is_pm(inner_pair) {
-                        is_pm = res;
-                    }
+                    is_pm = process_is_pm(inner_pair);
                 }
                 _ => {
                     return Err(ParseDateError::ParseError(
@@ -308,21 +2766,293 @@ pub mod date_parser {
             }
         }
 
-        if is_pm && hour < 12 {
-            hour += 12;
-        } else if !is_pm && hour == 12 {
-            hour = 0;
+        match is_pm {
+            Some(true) if hour < 12 => hour += 12,
+            Some(false) if hour == 12 => hour = 0,
+            _ => {}
         }
 
-        let modified_datetime = change_time(datetime, hour, minute)?;
+        Ok((hour, minute))
+    }
 
-        Ok(modified_datetime)
+    /// Sets the time of `datetime` to a `time_of_day`, `attached_time` or `time_expression`: a specific
+    /// time such as "5:30 PM" or a day part from `config.vocabulary` such as "noon".
+    ///
+    /// When a time zone follows, as in "3pm EST" or "noon in Tokyo", the time is read on
+    /// the date of `datetime` in that zone and converted back to the reference zone.
+    pub fn process_time_of_day(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let mut time: Option<NaiveTime> = None;
+        let mut zone: Option<Zone> = None;
+
+        for inner_pair in pair.into_inner() {
+            match inner_pair.as_rule() {
+                Rule::specific_time => {
+                    let (hour, minute) = process_clock_time(inner_pair)?;
+                    time = Some(NaiveTime::from_hms_opt(hour, minute, 0).ok_or_else(|| {
+                        ParseDateError::ParseError("Invalid date or time components".to_string())
+                    })?);
+                }
+                Rule::named_time => {
+                    time = Some(config.vocabulary.day_part(inner_pair.as_str()).ok_or_else(
+                        || {
+                            ParseDateError::ParseError(format!(
+                                "Unknown time of day: {:?}",
+                                inner_pair.as_str()
+                            ))
+                        },
+                    )?);
+                }
+                Rule::bare_hour => {
+                    let hour = process_bare_hour(inner_pair, config)?;
+                    time = NaiveTime::from_hms_opt(hour, 0, 0);
+                }
+                Rule::time_zone => zone = Some(process_time_zone(inner_pair, config)?.zone),
+                Rule::time_of_day => return process_time_of_day(inner_pair, datetime, config),
+                _ => {
+                    return Err(ParseDateError::ParseError(
+                        "Unexpected rule in time of day".to_string(),
+                    ));
+                }
+            }
+        }
+
+        let time =
+            time.ok_or_else(|| ParseDateError::ParseError("Time not provided".to_string()))?;
+        let naive = datetime.date_naive().and_time(time);
+
+        let (resolved, resolution) = match &zone {
+            Some(zone) => {
+                zone.resolve_local(&naive, config.dst_policy)
+                    .map(|(zoned, resolution)| {
+                        (zoned.with_timezone(&datetime.timezone()), resolution)
+                    })?
+            }
+            None => to_local(naive, config)?,
+        };
+        record(
+            "process_time_of_day",
+            || match &zone {
+                Some(zone) => format!("{} in {}", naive, zone),
+                None => naive.to_string(),
+            },
+            &resolved,
+        );
+
+        Ok(Resolved::from((resolved, resolution)))
+    }
+
+    /// Resolves a `time_zone`: an offset from UTC, an IANA name, an abbreviation from
+    /// `config.zone_abbreviations` or a city whose IANA zone has the same name.
+    pub fn process_time_zone(
+        pair: Pair<'_, Rule>,
+        config: &ParserConfig,
+    ) -> Result<SourceZone, ParseDateError> {
+        let text = pair.as_str().to_string();
+        let inner_pair = pair
+            .into_inner()
+            .next()
+            .ok_or_else(|| ParseDateError::ParseError("Time zone not provided".to_string()))?;
+        let name = inner_pair.as_str();
+        let unknown = || ParseDateError::ParseError(format!("Unknown time zone: {:?}", name));
+
+        let (zone, alternatives) = match inner_pair.as_rule() {
+            Rule::utc_offset => (
+                Zone::Fixed(zones::parse_utc_offset(name).ok_or_else(unknown)?),
+                Vec::new(),
+            ),
+            Rule::iana_zone => (
+                Zone::Named(name.parse::<chrono_tz::Tz>().map_err(|_| unknown())?),
+                Vec::new(),
+            ),
+            Rule::zone_abbreviation => {
+                let candidates = config.zone_abbreviations.get(name).ok_or_else(unknown)?;
+                (candidates[0], candidates[1..].to_vec())
+            }
+            Rule::zone_city => (
+                Zone::Named(zones::zone_for_city(name).ok_or_else(unknown)?),
+                Vec::new(),
+            ),
+            _ => {
+                return Err(ParseDateError::ParseError(
+                    "Unexpected rule in time zone".to_string(),
+                ));
+            }
+        };
+
+        Ok(SourceZone {
+            text,
+            zone,
+            alternatives,
+        })
+    }
+
+    /// Resolves a weekday on its own, such as "Friday", according to
+    /// `config.readings.weekday`.
+    pub fn process_weekday_reading(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
+        let rule = pair
+            .into_inner()
+            .next()
+            .map(|day| day.as_rule())
+            .ok_or_else(|| ParseDateError::ParseError("Weekday not provided".to_string()))?;
+
+        match config.readings.weekday {
+            WeekdayReading::SameWeek => process_specific_day(rule, datetime),
+            WeekdayReading::Upcoming => {
+                shift_to_weekday(datetime, process_weekday(rule)?, Rule::this)
+            }
+            WeekdayReading::Previous => shift_to_weekday(
+                datetime - Duration::days(7),
+                process_weekday(rule)?,
+                Rule::this,
+            ),
+        }
+    }
+
+    /// Resolves a `numeric_date` such as "03/04" or "3.4.2026", keeping the time of day
+    /// of `datetime`. The order of month and day follows `config.readings.date_order`,
+    /// two-digit years are in the 2000s and dates without a year are in the year of
+    /// `datetime`.
+    pub fn process_numeric_date(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let mut parts: Vec<u32> = Vec::new();
+        let mut year = datetime.year();
+
+        for inner_pair in pair.clone().into_inner() {
+            let value = inner_pair
+                .as_str()
+                .parse::<u32>()
+                .map_err(|e| ParseDateError::ParseError(format!("Failed to parse date: {}", e)))?;
+            match inner_pair.as_rule() {
+                Rule::date_part => parts.push(value),
+                Rule::numeric_year if inner_pair.as_str().len() == 2 => {
+                    year = 2000 + value as i32;
+                }
+                Rule::numeric_year => year = value as i32,
+                _ => {
+                    return Err(ParseDateError::ParseError(format!(
+                        "Unexpected rule in numeric date: {:?}",
+                        inner_pair.as_rule()
+                    )));
+                }
+            }
+        }
+
+        let (month, day) = match (config.readings.date_order, parts.as_slice()) {
+            (DateOrder::MonthFirst, &[month, day]) => (month, day),
+            (DateOrder::DayFirst, &[day, month]) => (month, day),
+            _ => {
+                return Err(ParseDateError::ParseError(
+                    "Expected a day and a month".to_string(),
+                ));
+            }
+        };
+        let date = NaiveDate::from_ymd_opt(year, month, day).ok_or_else(|| {
+            ParseDateError::ParseError(format!("Invalid date: {:?}", pair.as_str()))
+        })?;
+
+        change_date(datetime, date, config.dst_policy).map(Resolved::from)
+    }
+
+    /// Resolves a `calendar_date` such as "March 3" or "the 3rd of March 2026", keeping
+    /// the time of `datetime`. Dates without a year are in the year of `datetime`.
+    pub fn process_calendar_date(
+        pair: Pair<'_, Rule>,
+        datetime: DateTime<ReferenceZone>,
+        config: &ParserConfig,
+    ) -> Result<Resolved, ParseDateError> {
+        let mut year = datetime.year();
+        let mut month = None;
+        let mut day = None;
+
+        for inner_pair in pair.clone().into_inner() {
+            match inner_pair.as_rule() {
+                Rule::month_name => {
+                    if let Some(name) = inner_pair.into_inner().next() {
+                        month = Some(process_month(name.as_rule())?);
+                    }
+                }
+                Rule::day_of_month => {
+                    let digits = inner_pair.as_str().trim_end_matches(char::is_alphabetic);
+                    day = Some(digits.parse::<u32>().map_err(|e| {
+                        ParseDateError::ParseError(format!("Failed to parse day: {}", e))
+                    })?);
+                }
+                Rule::year_number => {
+                    year = inner_pair.as_str().parse::<i32>().map_err(|e| {
+                        ParseDateError::ParseError(format!("Failed to parse year: {}", e))
+                    })?;
+                }
+                _ => {
+                    return Err(ParseDateError::ParseError(format!(
+                        "Unexpected rule in calendar date: {:?}",
+                        inner_pair.as_rule()
+                    )));
+                }
+            }
+        }
+
+        let date = month
+            .zip(day)
+            .and_then(|(month, day)| NaiveDate::from_ymd_opt(year, month, day))
+            .ok_or_else(|| {
+                ParseDateError::ParseError(format!("Invalid date: {:?}", pair.as_str()))
+            })?;
+
+        change_date(datetime, date, config.dst_policy).map(Resolved::from)
+    }
+
+    /// Returns the hour (0 to 23) of a `bare_hour` such as the "7" in "at 7".
+    ///
+    /// Hours from 1 to 12 are read according to `config.readings.hour`. The
+    /// [`HourReading::Likely`] reading takes 1 to 6 as PM, 7 to 11 as AM and 12 as noon,
+    /// following working hours.
+    pub fn process_bare_hour(
+        pair: Pair<'_, Rule>,
+        config: &ParserConfig,
+    ) -> Result<u32, ParseDateError> {
+        let hour = pair
+            .as_str()
+            .parse::<u32>()
+            .map_err(|e| ParseDateError::ParseError(format!("Failed to parse hour: {}", e)))?;
+
+        if hour > 23 {
+            return Err(ParseDateError::ParseError(format!(
+                "Invalid hour: {:?}",
+                hour
+            )));
+        }
+        if hour == 0 || hour > 12 {
+            return Ok(hour);
+        }
+
+        let is_pm = match config.readings.hour {
+            HourReading::Likely => hour <= 6 || hour == 12,
+            HourReading::Am => false,
+            HourReading::Pm => true,
+        };
+        Ok(match (is_pm, hour) {
+            (true, 12) => 12,
+            (true, _) => hour + 12,
+            (false, 12) => 0,
+            (false, _) => hour,
+        })
     }
 
     pub fn process_specific_day(
         rule: Rule,
-        datetime: DateTime<Local>,
-    ) -> Result<DateTime<Local>, ParseDateError> {
+        datetime: DateTime<ReferenceZone>,
+    ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
         let target_weekday = process_weekday(rule)?;
         let current_weekday = datetime.weekday();
 
@@ -355,34 +3085,61 @@ pub mod date_parser {
         }
     }
 
-    pub fn change_time(
-        datetime: DateTime<Local>,
+    /// Sets the time of `datetime` to `hour`:`minute`, resolving times affected by
+    /// daylight saving time with the default [`DstPolicy`].
+    pub fn change_time<Tz: TimeZone>(
+        datetime: DateTime<Tz>,
         hour: u32,
         minute: u32,
-    ) -> Result<DateTime<Local>, ParseDateError> {
-        match Local.with_ymd_and_hms(
-            datetime.year(),
-            datetime.month(),
-            datetime.day(),
-            hour,
-            minute,
-            0,
-        ) {
-            chrono::LocalResult::Single(new_datetime) => Ok(new_datetime),
-            chrono::LocalResult::None => Err(ParseDateError::ParseError(
-                "Invalid date or time components".to_string(),
-            )),
-            chrono::LocalResult::Ambiguous(_, _) => Err(ParseDateError::ParseError(
-                "Ambiguous date and time".to_string(),
-            )),
-        }
+    ) -> Result<DateTime<Tz>, ParseDateError> {
+        change_time_with_policy(datetime, hour, minute, DstPolicy::default())
+            .map(|(datetime, _)| datetime)
+    }
+
+    /// Sets the time of `datetime` to `hour`:`minute` in its time zone. A time that
+    /// occurs twice or not at all on that day is resolved with `policy`, and the
+    /// resolution that was applied is returned along with the result.
+    pub fn change_time_with_policy<Tz: TimeZone>(
+        datetime: DateTime<Tz>,
+        hour: u32,
+        minute: u32,
+        policy: DstPolicy,
+    ) -> Result<(DateTime<Tz>, Option<DstResolution>), ParseDateError> {
+        let naive = datetime
+            .date_naive()
+            .and_hms_opt(hour, minute, 0)
+            .ok_or_else(|| {
+                ParseDateError::ParseError("Invalid date or time components".to_string())
+            })?;
+
+        zones::resolve_local(&datetime.timezone(), &naive, policy)
+    }
+
+    /// Interprets a naive date and time in the reference zone, `ParserConfig::zone`,
+    /// resolving times affected by daylight saving time with `ParserConfig::dst_policy`.
+    pub fn to_local(
+        naive: NaiveDateTime,
+        config: &ParserConfig,
+    ) -> Result<(DateTime<ReferenceZone>, Option<DstResolution>), ParseDateError> {
+        zones::resolve_local(&config.reference_zone(), &naive, config.dst_policy)
+    }
+
+    /// Moves `datetime` to `date`, keeping its time of day. A time of day that occurs
+    /// twice or not at all on `date` is resolved with `policy`.
+    pub fn change_date(
+        datetime: DateTime<ReferenceZone>,
+        date: NaiveDate,
+        policy: DstPolicy,
+    ) -> Result<(DateTime<ReferenceZone>, Option<DstResolution>), ParseDateError> {
+        let time = datetime.time().with_nanosecond(0).unwrap_or(NaiveTime::MIN);
+        zones::resolve_local(&datetime.timezone(), &date.and_time(time), policy)
     }
 
     pub fn shift_to_weekday(
-        now: DateTime<Local>,
+        now: DateTime<ReferenceZone>,
         target_weekday: Weekday,
         direction: Rule,
-    ) -> Result<DateTime<Local>, ParseDateError> {
+    ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
         let current_weekday = now.weekday();
 
         let num_from_curr = current_weekday.num_days_from_sunday() as i32;
@@ -422,9 +3179,19 @@ pub mod date_parser {
             )));
         }
 
-        // println!("days_difference {:?}", days_difference);
+        let shifted = now + Duration::days(days_difference as i64);
+        record(
+            "shift_to_weekday",
+            || {
+                format!(
+                    "{:?} {} from {}: {:+} days",
+                    direction, target_weekday, current_weekday, days_difference
+                )
+            },
+            &shifted,
+        );
 
-        Ok(now + Duration::days(days_difference as i64))
+        Ok(shifted)
     }
 
     pub fn process_is_pm(pair: Pair<'_, Rule>) -> Option<bool> {
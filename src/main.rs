@@ -1,34 +1,192 @@
-use natural_date_parser::*;
-use std::env;
+mod cli;
+
+use anyhow::anyhow;
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::{Generator, Shell};
+use cli::commands::{
+    self, DiffArgs, DurationArgs, ExpressionArgs, OccurrencesArgs, ParseArgs, RangeArgs,
+};
+use cli::context;
+use cli::error::{Error, Result};
+use cli::output::{self, DurationFormat, Format};
+use cli::repl::{self, Session};
+use natural_date_parser::ParserConfig;
+use std::io;
+use std::process::ExitCode;
+
+/// Converts natural language date and time expressions, such as "next Friday at 5 PM",
+/// into exact dates.
+///
+/// Without a command, the expression is parsed as with `parse`. Expressions can be
+/// written without quotes.
+///
+/// Exit status: 0 on success, 1 if an expression could not be parsed, 2 if the arguments
+/// are invalid and 3 for other errors.
+#[derive(Parser)]
+#[command(name = "natural-date-parser", version, arg_required_else_help = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[command(flatten)]
+    parse: ParseArgs,
+
+    /// Resolves expressions as if it were this moment: an RFC 3339 timestamp such as
+    /// "2026-01-15T09:00:00+01:00", a date such as "2026-01-15" or an expression such as
+    /// "next Monday at 9am".
+    #[arg(long, env = "NATURAL_DATE_NOW", value_name = "TIME", global = true)]
+    now: Option<String>,
+
+    /// Resolves expressions in this IANA time zone, such as "Europe/Kyiv", instead of the
+    /// system one.
+    #[arg(long, value_name = "ZONE", global = true)]
+    tz: Option<String>,
+
+    /// Converts results to this IANA time zone, such as "America/New_York" or "UTC",
+    /// before printing them.
+    #[arg(long, value_name = "ZONE", global = true)]
+    to_tz: Option<String>,
+
+    /// Shows project credits.
+    #[arg(long)]
+    credits: bool,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Resolves an expression, such as "next Friday at 5 PM", to a date and time.
+    Parse(ParseArgs),
+    /// Resolves a period, such as "Q2", "next week" or "March 2026", to the range it
+    /// covers, printed as start/end.
+    Range(RangeArgs),
+    /// Resolves a duration, such as "2 hours and 30 minutes", to an ISO 8601 duration.
+    Duration(DurationArgs),
+    /// Describes when an expression is relative to now, such as "in 3 days".
+    Humanize(ExpressionArgs),
+    /// Prints the time between two expressions, such as "today" and "end of quarter", in
+    /// days, business days, hours, weeks or in words.
+    #[command(visible_alias = "between")]
+    Diff(DiffArgs),
+    /// Lists the occurrences of a recurring date, such as "every other Friday at 9am" or
+    /// "every 2nd Tuesday", after now, after a given time or within a window.
+    #[command(visible_alias = "recur")]
+    Occurrences(OccurrencesArgs),
+    /// Shows how an expression is resolved: the result, the grammar rules that matched
+    /// with their spans, each intermediate datetime and any warnings.
+    Explain(ExpressionArgs),
+    /// Reads expressions interactively and shows the resolved date, the rules that
+    /// matched and any warnings.
+    Repl,
+    /// Prints a completion script for a shell, for example:
+    /// natural-date-parser completions bash > ~/.local/share/bash-completion/completions/natural-date-parser
+    Completions {
+        /// The shell the script is for.
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+    /// Prints the manual page in roff, for example: natural-date-parser man | man -l -
+    Man {
+        /// Prints the page of this command instead, such as "occurrences".
+        command: Option<String>,
+    },
+}
 
 /// CLI interface
-fn main() -> anyhow::Result<()> {
-    let args: Vec<String> = env::args().collect();
+fn main() -> ExitCode {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) if e.use_stderr() && json_requested(std::env::args()) => {
+            // clap's message, without its "error: " prefix and the usage that follows.
+            let message = e.to_string();
+            let message = message.lines().next().unwrap_or_default();
+            let message = message.strip_prefix("error: ").unwrap_or(message);
+            let e = Error::Usage(anyhow!("{}", message));
+            eprintln!("{}", output::render_failure(&e, Format::Json));
+            return ExitCode::from(e.exit_code());
+        }
+        Err(e) => e.exit(),
+    };
 
-    if args.len() < 2 {
-        print_help();
-        return Ok(());
+    let format = cli.format();
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            if !matches!(e, Error::Parse) {
+                eprintln!("{}", output::render_failure(&e, format));
+            }
+            ExitCode::from(e.exit_code())
+        }
     }
+}
 
-    match args[1].as_str() {
-        "--help" => print_help(),
-        "--credits" => print_credits(),
-        date_string => match date_parser::from_string(date_string) {
-            Ok(parsed) => println!("{:#?}", parsed),
-            Err(e) => eprintln!("Error: {}", e),
-        },
+impl Cli {
+    /// The format the command prints its results in, which its errors are printed in too.
+    fn format(&self) -> Format {
+        match &self.command {
+            None => self.parse.output.format,
+            Some(Command::Parse(args)) => args.output.format,
+            Some(Command::Range(args)) => args.output.format,
+            Some(Command::Occurrences(args)) => args.output.format,
+            Some(Command::Duration(args)) if args.format == DurationFormat::Json => Format::Json,
+            _ => Format::Rfc3339,
+        }
     }
+}
 
-    Ok(())
+/// Whether `args`, which clap rejected, ask for `--format json`, so that the error is
+/// printed as JSON too.
+fn json_requested(args: impl Iterator<Item = String>) -> bool {
+    let args: Vec<String> = args.collect();
+    args.iter().any(|arg| arg == "--format=json")
+        || args
+            .windows(2)
+            .any(|pair| pair[0] == "--format" && pair[1] == "json")
 }
 
-fn print_help() {
-    println!("Date Parser CLI - Usage:");
-    println!(
-        "  cargo run <date_string>     Parses a date written in English and displays its components."
-    );
-    println!("  cargo run -- --help        Displays help information.");
-    println!("  cargo run -- --credits     Shows project credits.");
+fn run(cli: Cli) -> Result<()> {
+    if cli.credits {
+        print_credits();
+        return Ok(());
+    }
+    let mut config = ParserConfig::default();
+    if let Some(zone) = &cli.tz {
+        config.zone = Some(context::reference_zone(zone).map_err(Error::Usage)?);
+    }
+    if let Some(zone) = &cli.to_tz {
+        config.target_zone = Some(context::target_zone(zone).map_err(Error::Usage)?);
+    }
+    if let Some(now) = &cli.now {
+        config.now = Some(context::reference_time(now, &config).map_err(Error::Usage)?);
+    }
+
+    match &cli.command {
+        None => commands::parse(&cli.parse, &config),
+        Some(Command::Parse(args)) => commands::parse(args, &config),
+        Some(Command::Range(args)) => commands::range(args, &config),
+        Some(Command::Duration(args)) => commands::duration(args, &config),
+        Some(Command::Humanize(args)) => commands::humanize(args, &config),
+        Some(Command::Diff(args)) => commands::diff(args, &config),
+        Some(Command::Occurrences(args)) => commands::occurrences(args, &config),
+        Some(Command::Explain(args)) => commands::explain(args, &config),
+        Some(Command::Repl) => Ok(repl::run(Session::new(config))?),
+        Some(Command::Completions { shell }) => {
+            let mut command = Cli::command();
+            command.set_bin_name(command.get_name().to_string());
+            command.build();
+            Ok(shell.try_generate(&command, &mut io::stdout())?)
+        }
+        Some(Command::Man { command: name }) => {
+            let mut command = Cli::command();
+            command.build();
+            if let Some(name) = name {
+                command = command
+                    .find_subcommand(name)
+                    .cloned()
+                    .ok_or_else(|| Error::Usage(anyhow!("Unknown command: {:?}", name)))?;
+            }
+            Ok(clap_mangen::Man::new(command).render(&mut io::stdout())?)
+        }
+    }
 }
 
 fn print_credits() {
@@ -0,0 +1,139 @@
+//! Words defined by applications at runtime, such as "payday", "standup" or "sprint".
+//!
+//! The grammar accepts any word that is not a keyword in the positions of a day, a time
+//! of day or a time unit. Those words are looked up in the [`Vocabulary`] set as
+//! `ParserConfig::vocabulary` when the expression is resolved, so new terms do not
+//! require changes to `grammar.pest`.
+
+use crate::holidays::normalize_name;
+use crate::{ParseDateError, Rule};
+use chrono::{NaiveDate, NaiveTime};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Decides whether a date is an occurrence of a named anchor.
+pub type AnchorPredicate = Arc<dyn Fn(NaiveDate) -> bool + Send + Sync>;
+
+/// Named anchors, day parts and unit synonyms registered by an application.
+///
+/// ```
+/// use chrono::{Datelike, NaiveTime};
+/// use natural_date_parser::Rule;
+/// use natural_date_parser::vocabulary::Vocabulary;
+///
+/// let mut vocabulary = Vocabulary::default();
+/// vocabulary.add_anchor("payday", |date| {
+///     date.day() == 15 || date.succ_opt().is_some_and(|next| next.day() == 1)
+/// });
+/// vocabulary.add_day_part("standup", NaiveTime::from_hms_opt(9, 45, 0).unwrap());
+/// vocabulary.add_unit("sprint", 2, Rule::week_s).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct Vocabulary {
+    anchors: HashMap<String, AnchorPredicate>,
+    day_parts: HashMap<String, NaiveTime>,
+    units: HashMap<String, (i32, Rule)>,
+}
+
+impl Vocabulary {
+    /// Creates a vocabulary without any words, not even the defaults.
+    pub fn new() -> Self {
+        Self {
+            anchors: HashMap::new(),
+            day_parts: HashMap::new(),
+            units: HashMap::new(),
+        }
+    }
+
+    /// Registers a named day such as "payday". `is_occurrence` returns `true` for every
+    /// date the anchor falls on.
+    ///
+    /// "payday" resolves to the first occurrence from today on, "next payday" to the
+    /// first one after today, "last payday" to the most recent one before today and
+    /// "payday 2027" to the first one in that year.
+    pub fn add_anchor<F>(&mut self, name: &str, is_occurrence: F)
+    where
+        F: Fn(NaiveDate) -> bool + Send + Sync + 'static,
+    {
+        self.anchors
+            .insert(normalize_name(name), Arc::new(is_occurrence));
+    }
+
+    /// Registers a one-word name for a time of day, usable after "at" ("tomorrow at
+    /// standup") or on its own for today.
+    pub fn add_day_part(&mut self, name: &str, time: NaiveTime) {
+        self.day_parts.insert(normalize_name(name), time);
+    }
+
+    /// Registers a one-word unit worth `amount` of `unit`, so with "sprint" as 2
+    /// `Rule::week_s`, "in 3 sprints" means in 6 weeks. The plural form with a trailing
+    /// "s" is accepted automatically.
+    ///
+    /// Returns an error if `unit` is not one of the time unit rules, such as
+    /// `Rule::day_s` or `Rule::business_day_s`.
+    pub fn add_unit(&mut self, name: &str, amount: i32, unit: Rule) -> Result<(), ParseDateError> {
+        match unit {
+            Rule::minute_s
+            | Rule::hour_s
+            | Rule::business_day_s
+            | Rule::day_s
+            | Rule::week_s
+            | Rule::month_s
+            | Rule::year_s => {
+                self.units.insert(normalize_name(name), (amount, unit));
+                Ok(())
+            }
+            _ => Err(ParseDateError::ParseError(format!(
+                "Invalid time unit: {:?}",
+                unit
+            ))),
+        }
+    }
+
+    /// Returns the predicate of the anchor called `name`.
+    pub fn anchor(&self, name: &str) -> Option<&AnchorPredicate> {
+        self.anchors.get(&normalize_name(name))
+    }
+
+    /// Returns the time of the day part called `name`.
+    pub fn day_part(&self, name: &str) -> Option<NaiveTime> {
+        self.day_parts.get(&normalize_name(name)).copied()
+    }
+
+    /// Returns the amount and unit of the unit called `name`, in singular or plural form.
+    pub fn unit(&self, name: &str) -> Option<(i32, Rule)> {
+        let name = normalize_name(name);
+
+        self.units.get(&name).copied().or_else(|| {
+            name.strip_suffix('s')
+                .and_then(|singular| self.units.get(singular).copied())
+        })
+    }
+}
+
+impl Default for Vocabulary {
+    /// A vocabulary with the day parts "noon" and "midnight" and the unit "fortnight".
+    fn default() -> Self {
+        let mut vocabulary = Self::new();
+        vocabulary.add_day_part("noon", NaiveTime::from_hms_opt(12, 0, 0).unwrap());
+        vocabulary.add_day_part("midnight", NaiveTime::MIN);
+        vocabulary
+            .units
+            .insert("fortnight".to_string(), (2, Rule::week_s));
+        vocabulary
+    }
+}
+
+impl fmt::Debug for Vocabulary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut anchors: Vec<_> = self.anchors.keys().collect();
+        anchors.sort();
+
+        f.debug_struct("Vocabulary")
+            .field("anchors", &anchors)
+            .field("day_parts", &self.day_parts)
+            .field("units", &self.units)
+            .finish()
+    }
+}
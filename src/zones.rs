@@ -0,0 +1,444 @@
+//! Time zones written in expressions, such as "3pm EST", "9:00 UTC+2", "10 AM
+//! Europe/Kyiv" or "noon in Tokyo".
+//!
+//! Abbreviations are looked up in [`ZoneAbbreviations`], set as
+//! `ParserConfig::zone_abbreviations`. Several abbreviations name more than one zone
+//! ("CST" is both US Central and China Standard Time); the first candidate is used and
+//! the others are kept in [`SourceZone::alternatives`].
+//!
+//! Wall-clock times that occur twice or not at all because of daylight saving time are
+//! resolved according to a [`DstPolicy`].
+
+use crate::ParseDateError;
+use crate::holidays::normalize_name;
+use chrono::{
+    DateTime, Duration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, NaiveTime,
+    Offset, TimeZone,
+};
+use chrono_tz::{TZ_VARIANTS, Tz};
+use std::collections::HashMap;
+use std::fmt;
+
+/// A time zone: either a fixed offset from UTC or a zone from the IANA database, which
+/// follows daylight saving time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zone {
+    /// A fixed offset, as written in "UTC+2" or implied by "EST".
+    Fixed(FixedOffset),
+    /// An IANA zone, as written in "Europe/Kyiv" or implied by "PT".
+    Named(Tz),
+}
+
+impl Zone {
+    /// Interprets a wall-clock date and time in this zone, resolving times affected by
+    /// daylight saving time according to `policy` as [`resolve_local`] does.
+    pub fn resolve_local(
+        &self,
+        naive: &NaiveDateTime,
+        policy: DstPolicy,
+    ) -> Result<(DateTime<FixedOffset>, Option<DstResolution>), ParseDateError> {
+        match self {
+            Zone::Fixed(offset) => resolve_local(offset, naive, policy),
+            Zone::Named(tz) => resolve_local(tz, naive, policy)
+                .map(|(datetime, resolution)| (datetime.fixed_offset(), resolution)),
+        }
+    }
+
+    /// Interprets a wall-clock date and time in this zone.
+    pub fn from_local_datetime(&self, naive: &NaiveDateTime) -> LocalResult<DateTime<FixedOffset>> {
+        match self {
+            Zone::Fixed(offset) => offset.from_local_datetime(naive),
+            Zone::Named(tz) => tz
+                .from_local_datetime(naive)
+                .map(|datetime| datetime.fixed_offset()),
+        }
+    }
+
+    /// Converts `datetime` to this zone.
+    pub fn convert<Tz2: TimeZone>(&self, datetime: &DateTime<Tz2>) -> DateTime<FixedOffset> {
+        match self {
+            Zone::Fixed(offset) => datetime.with_timezone(offset),
+            Zone::Named(tz) => datetime.with_timezone(tz).fixed_offset(),
+        }
+    }
+}
+
+impl fmt::Display for Zone {
+    /// Formats fixed offsets as "UTC", "UTC+02:00" or "UTC-05:30" and IANA zones by name.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Zone::Fixed(offset) if offset.local_minus_utc() == 0 => write!(f, "UTC"),
+            Zone::Fixed(offset) => {
+                let seconds = offset.local_minus_utc();
+                let sign = if seconds < 0 { '-' } else { '+' };
+                let minutes = seconds.abs() / 60;
+                write!(f, "UTC{}{:02}:{:02}", sign, minutes / 60, minutes % 60)
+            }
+            Zone::Named(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+/// The time zone expressions are resolved in and report their results in: the system's
+/// local time zone, or the IANA zone set as `ParserConfig::zone`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferenceZone {
+    /// The system's local time zone.
+    Local,
+    /// An IANA zone, as set with `--tz Europe/Kyiv`.
+    Named(Tz),
+}
+
+impl ReferenceZone {
+    /// The current time in this zone.
+    pub fn now(&self) -> DateTime<ReferenceZone> {
+        Local::now().with_timezone(self)
+    }
+}
+
+/// The offset from UTC in effect in a [`ReferenceZone`] at some instant.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct ReferenceOffset {
+    zone: ReferenceZone,
+    offset: FixedOffset,
+}
+
+impl Offset for ReferenceOffset {
+    fn fix(&self) -> FixedOffset {
+        self.offset
+    }
+}
+
+impl fmt::Debug for ReferenceOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.offset, f)
+    }
+}
+
+impl fmt::Display for ReferenceOffset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.offset, f)
+    }
+}
+
+impl TimeZone for ReferenceZone {
+    type Offset = ReferenceOffset;
+
+    fn from_offset(offset: &ReferenceOffset) -> Self {
+        offset.zone
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<ReferenceOffset> {
+        self.offset_from_local_datetime(&local.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<ReferenceOffset> {
+        let offset = match self {
+            ReferenceZone::Local => Local.offset_from_local_datetime(local),
+            ReferenceZone::Named(tz) => tz.offset_from_local_datetime(local).map(|o| o.fix()),
+        };
+        offset.map(|offset| ReferenceOffset {
+            zone: *self,
+            offset,
+        })
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> ReferenceOffset {
+        self.offset_from_utc_datetime(&utc.and_time(NaiveTime::MIN))
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> ReferenceOffset {
+        let offset = match self {
+            ReferenceZone::Local => Local.offset_from_utc_datetime(utc),
+            ReferenceZone::Named(tz) => tz.offset_from_utc_datetime(utc).fix(),
+        };
+        ReferenceOffset {
+            zone: *self,
+            offset,
+        }
+    }
+}
+
+/// The time zone written in an expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceZone {
+    /// The zone as written, for example "EST" or "in Tokyo".
+    pub text: String,
+    /// The zone the time was interpreted in.
+    pub zone: Zone,
+    /// Other zones an ambiguous abbreviation can stand for, in order of preference.
+    pub alternatives: Vec<Zone>,
+}
+
+/// Time zone abbreviations and the zones they stand for, in order of preference.
+///
+/// ```
+/// use chrono::FixedOffset;
+/// use natural_date_parser::zones::{Zone, ZoneAbbreviations};
+///
+/// let mut abbreviations = ZoneAbbreviations::default();
+/// let india = Zone::Fixed(FixedOffset::east_opt(5 * 3600 + 1800).unwrap());
+/// let ireland = Zone::Fixed(FixedOffset::east_opt(3600).unwrap());
+///
+/// assert_eq!(abbreviations.get("IST").unwrap()[0], india);
+/// abbreviations.prefer("IST", ireland);
+/// assert_eq!(abbreviations.get("IST").unwrap()[0], ireland);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZoneAbbreviations {
+    zones: HashMap<String, Vec<Zone>>,
+}
+
+impl ZoneAbbreviations {
+    /// Creates a table without any abbreviations.
+    pub fn new() -> Self {
+        Self {
+            zones: HashMap::new(),
+        }
+    }
+
+    /// Adds `zone` as the least preferred meaning of `abbreviation`.
+    pub fn add(&mut self, abbreviation: &str, zone: Zone) {
+        let zones = self.zones.entry(abbreviation.to_uppercase()).or_default();
+        if !zones.contains(&zone) {
+            zones.push(zone);
+        }
+    }
+
+    /// Makes `zone` the preferred meaning of `abbreviation`, keeping the other meanings
+    /// as alternatives.
+    pub fn prefer(&mut self, abbreviation: &str, zone: Zone) {
+        let zones = self.zones.entry(abbreviation.to_uppercase()).or_default();
+        zones.retain(|candidate| *candidate != zone);
+        zones.insert(0, zone);
+    }
+
+    /// Returns the zones `abbreviation` stands for, the preferred one first.
+    pub fn get(&self, abbreviation: &str) -> Option<&[Zone]> {
+        self.zones
+            .get(&abbreviation.to_uppercase())
+            .map(Vec::as_slice)
+            .filter(|zones| !zones.is_empty())
+    }
+}
+
+impl Default for ZoneAbbreviations {
+    /// Common abbreviations of North American, European, Asian and Australian zones.
+    /// Abbreviations of a region ("ET", "PT") follow daylight saving time, while those of
+    /// standard or summer time ("EST", "PDT") are fixed offsets.
+    fn default() -> Self {
+        const FIXED: [(&str, i32); 44] = [
+            ("UTC", 0),
+            ("GMT", 0),
+            ("Z", 0),
+            ("EST", -300),
+            ("EDT", -240),
+            ("CST", -360),
+            ("CST", 480),
+            ("CDT", -300),
+            ("MST", -420),
+            ("MDT", -360),
+            ("PST", -480),
+            ("PDT", -420),
+            ("AKST", -540),
+            ("AKDT", -480),
+            ("HST", -600),
+            ("AST", -240),
+            ("AST", 180),
+            ("ADT", -180),
+            ("NST", -210),
+            ("BST", 60),
+            ("BST", 360),
+            ("IST", 330),
+            ("IST", 60),
+            ("IST", 120),
+            ("WET", 0),
+            ("WEST", 60),
+            ("CET", 60),
+            ("CEST", 120),
+            ("EET", 120),
+            ("EEST", 180),
+            ("MSK", 180),
+            ("GST", 240),
+            ("PKT", 300),
+            ("SGT", 480),
+            ("HKT", 480),
+            ("JST", 540),
+            ("KST", 540),
+            ("AWST", 480),
+            ("ACST", 570),
+            ("ACDT", 630),
+            ("AEST", 600),
+            ("AEDT", 660),
+            ("NZST", 720),
+            ("NZDT", 780),
+        ];
+        const REGIONS: [(&str, Tz); 4] = [
+            ("ET", Tz::America__New_York),
+            ("CT", Tz::America__Chicago),
+            ("MT", Tz::America__Denver),
+            ("PT", Tz::America__Los_Angeles),
+        ];
+
+        let mut abbreviations = Self::new();
+        for (abbreviation, minutes) in FIXED {
+            if let Some(offset) = FixedOffset::east_opt(minutes * 60) {
+                abbreviations.add(abbreviation, Zone::Fixed(offset));
+            }
+        }
+        for (abbreviation, tz) in REGIONS {
+            abbreviations.add(abbreviation, Zone::Named(tz));
+        }
+        abbreviations
+    }
+}
+
+/// How wall-clock times affected by daylight saving time are resolved.
+///
+/// When clocks go back, the times in the repeated hour occur twice; when clocks go
+/// forward, the times in the skipped hour do not occur at all. The default picks the
+/// earlier of two times and moves skipped times forward by the length of the gap, so
+/// 2:30 AM on the day New York moves to summer time becomes 3:30 AM.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DstPolicy {
+    /// What to do with a time that occurs twice.
+    pub ambiguous: AmbiguousTime,
+    /// What to do with a time that does not occur.
+    pub skipped: SkippedTime,
+}
+
+/// Resolution of a wall-clock time that occurs twice.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AmbiguousTime {
+    /// The first occurrence, still on summer time.
+    #[default]
+    Earliest,
+    /// The second occurrence, already on standard time.
+    Latest,
+    /// Return an error.
+    Error,
+}
+
+/// Resolution of a wall-clock time that falls in the gap when clocks go forward.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SkippedTime {
+    /// Move the time forward by the length of the gap, as if the clocks had not changed
+    /// yet.
+    #[default]
+    ShiftForward,
+    /// Move the time back by the length of the gap, as if the clocks had already
+    /// changed.
+    ShiftBack,
+    /// Return an error.
+    Error,
+}
+
+/// How a time affected by daylight saving time was resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DstResolution {
+    /// The first of two occurrences was used.
+    Earliest,
+    /// The second of two occurrences was used.
+    Latest,
+    /// A skipped time was moved forward.
+    ShiftedForward,
+    /// A skipped time was moved back.
+    ShiftedBack,
+}
+
+/// Interprets a wall-clock date and time in `tz`, applying `policy` when the time occurs
+/// twice or not at all. Returns the instant along with the resolution that was applied,
+/// if any.
+pub fn resolve_local<Tz: TimeZone>(
+    tz: &Tz,
+    naive: &NaiveDateTime,
+    policy: DstPolicy,
+) -> Result<(DateTime<Tz>, Option<DstResolution>), ParseDateError> {
+    match tz.from_local_datetime(naive) {
+        LocalResult::Single(datetime) => Ok((datetime, None)),
+        LocalResult::Ambiguous(earliest, latest) => match policy.ambiguous {
+            AmbiguousTime::Earliest => Ok((earliest, Some(DstResolution::Earliest))),
+            AmbiguousTime::Latest => Ok((latest, Some(DstResolution::Latest))),
+            AmbiguousTime::Error => Err(ParseDateError::ParseError(format!(
+                "{} occurs twice because of a daylight saving time change",
+                naive
+            ))),
+        },
+        LocalResult::None => {
+            // Gaps are at most a day long and never follow each other within a day, so
+            // the offsets a day before and after are those on either side of the gap.
+            let offset_around = |days: i64| {
+                let instant = naive.checked_add_signed(Duration::days(days))?;
+                Some(tz.offset_from_utc_datetime(&instant).fix())
+            };
+            let (offset, resolution) = match policy.skipped {
+                SkippedTime::ShiftForward => (offset_around(-1), DstResolution::ShiftedForward),
+                SkippedTime::ShiftBack => (offset_around(1), DstResolution::ShiftedBack),
+                SkippedTime::Error => {
+                    return Err(ParseDateError::ParseError(format!(
+                        "{} is skipped by a daylight saving time change",
+                        naive
+                    )));
+                }
+            };
+            let instant = offset
+                .and_then(|offset| {
+                    naive.checked_sub_signed(Duration::seconds(offset.local_minus_utc() as i64))
+                })
+                .ok_or_else(|| {
+                    ParseDateError::ParseError("Invalid date or time components".to_string())
+                })?;
+
+            Ok((tz.from_utc_datetime(&instant), Some(resolution)))
+        }
+    }
+}
+
+/// Parses an offset such as "UTC+2", "GMT-05:30", "UTC+0530" or "+02:00".
+pub fn parse_utc_offset(text: &str) -> Option<FixedOffset> {
+    let text = text.trim();
+    let rest = ["UTC", "GMT"]
+        .iter()
+        .find_map(|prefix| {
+            text.get(..prefix.len())
+                .filter(|start| start.eq_ignore_ascii_case(prefix))
+                .map(|_| &text[prefix.len()..])
+        })
+        .unwrap_or(text);
+
+    let sign = match rest.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+    let digits: String = rest[1..].chars().filter(|c| *c != ':').collect();
+    let (hours, minutes) = match digits.len() {
+        1 | 2 => (digits.parse::<i32>().ok()?, 0),
+        3 | 4 => {
+            let split = digits.len() - 2;
+            (
+                digits[..split].parse::<i32>().ok()?,
+                digits[split..].parse::<i32>().ok()?,
+            )
+        }
+        _ => return None,
+    };
+    if hours > 14 || minutes > 59 {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+/// Finds the IANA zone of a city, such as "Tokyo" for Asia/Tokyo or "new york" for
+/// America/New_York, by comparing the last part of every zone name.
+pub fn zone_for_city(city: &str) -> Option<Tz> {
+    let city = normalize_name(city);
+
+    TZ_VARIANTS.iter().copied().find(|tz| {
+        tz.name()
+            .rsplit('/')
+            .next()
+            .is_some_and(|name| normalize_name(&name.replace('_', " ")) == city)
+    })
+}
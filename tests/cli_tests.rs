@@ -0,0 +1,786 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{Local, TimeZone};
+    use serde_json::Value;
+    use std::io::Write;
+    use std::process::{Command, Output, Stdio};
+
+    fn run(args: &[&str]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_natural-date-parser"))
+            .args(args)
+            .output()
+            .unwrap()
+    }
+
+    fn run_with_stdin(args: &[&str], stdin: &str) -> Output {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_natural-date-parser"))
+            .args(args)
+            .env("HOME", std::env::temp_dir())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(stdin.as_bytes())
+            .unwrap();
+        child.wait_with_output().unwrap()
+    }
+
+    fn stdout(output: &Output) -> String {
+        String::from_utf8(output.stdout.clone())
+            .unwrap()
+            .trim()
+            .to_string()
+    }
+
+    fn stderr(output: &Output) -> String {
+        String::from_utf8(output.stderr.clone())
+            .unwrap()
+            .trim()
+            .to_string()
+    }
+
+    #[test]
+    fn test_default_format_is_rfc3339() {
+        let output = run(&["March 3 2025 at 5 PM"]);
+        let expected = Local.with_ymd_and_hms(2025, 3, 3, 17, 0, 0).unwrap();
+
+        assert_eq!(stdout(&output), expected.to_rfc3339());
+    }
+
+    #[test]
+    fn test_unix_and_custom_formats() {
+        let expected = Local.with_ymd_and_hms(2025, 3, 3, 17, 0, 0).unwrap();
+
+        let output = run(&["--format", "unix", "March 3 2025 at 5 PM"]);
+        assert_eq!(stdout(&output), expected.timestamp().to_string());
+
+        let output = run(&[
+            "--format",
+            "custom",
+            "--pattern",
+            "%Y-%m-%d %H:%M",
+            "March 3 2025 at 5 PM",
+        ]);
+        assert_eq!(stdout(&output), "2025-03-03 17:00");
+    }
+
+    #[test]
+    fn test_json_format() {
+        let output = run(&["--format", "json", "March 3 2025 at 5 PM UTC"]);
+        let json: Value = serde_json::from_str(&stdout(&output)).unwrap();
+
+        assert_eq!(json["input"], "March 3 2025 at 5 PM UTC");
+        assert_eq!(json["granularity"], "time");
+        assert_eq!(json["timestamp"], 1741021200);
+        assert_eq!(json["zone"]["source"], "UTC");
+        assert_eq!(json["zone"]["source_zone"], "UTC");
+        assert!(json["uncertainty"].is_null());
+        assert_eq!(json["warnings"], Value::Array(Vec::new()));
+    }
+
+    #[test]
+    fn test_json_warnings_and_uncertainty() {
+        let output = run(&["--format", "json", "03/04/2025"]);
+        let json: Value = serde_json::from_str(&stdout(&output)).unwrap();
+        assert_eq!(json["granularity"], "day");
+        assert_eq!(json["warnings"].as_array().unwrap().len(), 1);
+
+        let output = run(&["--format", "json", "late March 2025"]);
+        let json: Value = serde_json::from_str(&stdout(&output)).unwrap();
+        assert!(json["uncertainty"]["start"].is_string());
+        assert!(json["uncertainty"]["end"].is_string());
+    }
+
+    #[test]
+    fn test_json_warns_about_every_dst_resolution() {
+        // October 27, 2024 at 1:30 AM occurs twice in London and March 27, 2022 at
+        // 1:30 AM does not occur at all.
+        let output = run(&[
+            "--tz",
+            "Europe/London",
+            "--format",
+            "json",
+            "31 months before October 27 2024 at 1:30 AM",
+        ]);
+        let json: Value = serde_json::from_str(&stdout(&output)).unwrap();
+        assert_eq!(json["datetime"], "2022-03-27T02:30:00+01:00");
+        assert_eq!(
+            json["warnings"],
+            serde_json::json!([
+                "The time occurs twice because of daylight saving time; using the earlier one",
+                "The time is skipped by daylight saving time; shifted forward"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_json_errors_go_to_stderr() {
+        let output = run(&["--format", "json", "not a date"]);
+        let json: Value = serde_json::from_str(&stderr(&output)).unwrap();
+
+        assert_eq!(stdout(&output), "");
+        assert_eq!(json["input"], "not a date");
+        assert_eq!(json["error"]["kind"], "parse");
+        assert!(json["error"]["message"].is_string());
+    }
+
+    #[test]
+    fn test_json_usage_and_failure_errors() {
+        for args in [
+            &["--format", "json", "--tz", "Mars/Base", "today"][..],
+            &["--format", "json", "--bogus", "today"],
+        ] {
+            let output = run(args);
+            let json: Value = serde_json::from_str(&stderr(&output)).unwrap();
+            assert_eq!(output.status.code(), Some(2));
+            assert_eq!(json["error"]["kind"], "usage");
+            assert!(json["error"]["message"].is_string());
+        }
+
+        let output = run(&["--format=json", "--input", "/nonexistent/dates.txt"]);
+        let json: Value = serde_json::from_str(&stderr(&output)).unwrap();
+        assert_eq!(output.status.code(), Some(3));
+        assert_eq!(json["error"]["kind"], "failure");
+        assert!(
+            json["error"]["message"]
+                .as_str()
+                .unwrap()
+                .contains("/nonexistent/dates.txt")
+        );
+    }
+
+    #[test]
+    fn test_custom_format_needs_pattern() {
+        let output = run(&["--format", "custom", "today"]);
+
+        assert!(!output.status.success());
+        assert!(stderr(&output).contains("--pattern"));
+    }
+
+    const DAY: [&str; 3] = ["--format", "custom", "--pattern=%Y-%m-%d"];
+
+    #[test]
+    fn test_batch_one_result_per_line() {
+        let output = run_with_stdin(
+            &[&DAY[..], &["--batch"]].concat(),
+            "March 3 2025\n\nthe 4th of March 2025\n",
+        );
+
+        assert_eq!(stdout(&output), "2025-03-03\n\n2025-03-04");
+        assert_eq!(stderr(&output), "");
+    }
+
+    #[test]
+    fn test_batch_stops_at_first_error() {
+        let output = run_with_stdin(
+            &[&DAY[..], &["--batch"]].concat(),
+            "March 3 2025\nnot a date\nMarch 5 2025\n",
+        );
+
+        assert_eq!(stdout(&output), "2025-03-03");
+        assert!(stderr(&output).contains("1 of 2 expressions failed"));
+        assert!(stderr(&output).contains("--continue-on-error"));
+    }
+
+    #[test]
+    fn test_batch_continue_on_error() {
+        let output = run_with_stdin(
+            &[&DAY[..], &["--batch", "--continue-on-error"]].concat(),
+            "March 3 2025\nnot a date\nMarch 5 2025\n",
+        );
+
+        assert_eq!(stdout(&output), "2025-03-03\n\n2025-03-05");
+        assert!(stderr(&output).contains("1 of 3 expressions failed"));
+        assert!(stderr(&output).contains("line 2: \"not a date\""));
+    }
+
+    #[test]
+    fn test_batch_from_file() {
+        let path = std::env::temp_dir().join("natural_date_parser_batch_input.txt");
+        std::fs::write(&path, "March 3 2025\nMarch 4 2025\n").unwrap();
+
+        let output = run(&[&DAY[..], &["--input", path.to_str().unwrap()]].concat());
+        assert_eq!(stdout(&output), "2025-03-03\n2025-03-04");
+    }
+
+    #[test]
+    fn test_batch_csv_column_passthrough() {
+        let csv = "id,when,note\n1,March 3 2025,\"a, b\"\n2,nope,x\n";
+        let output = run_with_stdin(
+            &[
+                &DAY[..],
+                &[
+                    "--batch",
+                    "--csv",
+                    "--column",
+                    "when",
+                    "--passthrough",
+                    "--continue-on-error",
+                ],
+            ]
+            .concat(),
+            csv,
+        );
+
+        assert_eq!(
+            stdout(&output),
+            "id,when,note,parsed\n1,March 3 2025,\"a, b\",2025-03-03\n2,nope,x,"
+        );
+        assert!(stderr(&output).contains("line 3: \"nope\""));
+    }
+
+    #[test]
+    fn test_batch_csv_column_index_without_header() {
+        let output = run_with_stdin(
+            &[
+                &DAY[..],
+                &["--batch", "--csv", "--column", "2", "--no-header"],
+            ]
+            .concat(),
+            "1,March 3 2025\n2,March 4 2025\n",
+        );
+
+        assert_eq!(stdout(&output), "2025-03-03\n2025-03-04");
+    }
+
+    #[test]
+    fn test_now_and_tz() {
+        let output = run(&[
+            "--now",
+            "2024-11-11T12:00:00Z",
+            "--tz",
+            "Asia/Tokyo",
+            "tomorrow at 5 PM",
+        ]);
+        assert_eq!(stdout(&output), "2024-11-12T17:00:00+09:00");
+
+        let output = run(&[
+            "--now",
+            "2024-11-11T12:00:00+00:00",
+            "--tz",
+            "UTC",
+            "in 2 hours",
+        ]);
+        assert_eq!(stdout(&output), "2024-11-11T14:00:00+00:00");
+    }
+
+    #[test]
+    fn test_now_as_expression() {
+        let output = run(&["--tz", "UTC", "--now", "March 3 2025 at 9am", "in 2 hours"]);
+
+        assert_eq!(stdout(&output), "2025-03-03T11:00:00+00:00");
+    }
+
+    #[test]
+    fn test_now_from_environment() {
+        let output = Command::new(env!("CARGO_BIN_EXE_natural-date-parser"))
+            .args(["--tz", "Europe/Kyiv", "tomorrow at noon"])
+            .env("NATURAL_DATE_NOW", "2024-11-11T12:00:00+02:00")
+            .output()
+            .unwrap();
+        assert_eq!(stdout(&output), "2024-11-12T12:00:00+02:00");
+
+        let output = Command::new(env!("CARGO_BIN_EXE_natural-date-parser"))
+            .args(["--tz", "UTC", "--now", "2025-01-01T00:00:00Z", "today"])
+            .env("NATURAL_DATE_NOW", "2024-11-11T12:00:00Z")
+            .output()
+            .unwrap();
+        assert_eq!(stdout(&output), "2025-01-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_invalid_now_and_tz() {
+        assert!(!run(&["--tz", "Mars/Base", "today"]).status.success());
+        assert!(!run(&["--now", "not a time", "today"]).status.success());
+    }
+
+    #[test]
+    fn test_repl_shows_result_tree_and_warnings() {
+        let output = run_with_stdin(
+            &["repl", "--tz", "UTC", "--now", "2024-11-11T12:00:00Z"],
+            "tomorrow at 5 PM\n03/04/2025\n",
+        );
+        let text = stdout(&output);
+
+        assert!(text.contains("2024-11-12T17:00:00+00:00 (Tuesday)"));
+        assert!(text.contains("  relative_day_and_specific_time 0..16 \"tomorrow at 5 PM\""));
+        assert!(text.contains("      specific_time 12..16 \"5 PM\""));
+        assert!(text.contains("warning: \"03/04/2025\" could also mean"));
+    }
+
+    #[test]
+    fn test_repl_commands() {
+        let output = run_with_stdin(
+            &["repl", "--tz", "UTC"],
+            ":now 2024-11-11T12:00:00Z\n:tz Asia/Tokyo\ntomorrow at 5 PM\n:locale en-GB\n03/04/2025\n:quit\ntoday\n",
+        );
+        let text = stdout(&output);
+
+        assert!(text.contains("now: 2024-11-11T12:00:00+00:00"));
+        assert!(text.contains("tz: Asia/Tokyo (+09:00)"));
+        assert!(text.contains("2024-11-12T17:00:00+09:00 (Tuesday)"));
+        assert!(text.contains("locale: en-GB"));
+        assert!(text.contains("2025-04-03T21:00:00+09:00 (Thursday)"));
+        assert!(!text.contains("date_expression 0..5 \"today\""));
+    }
+
+    #[test]
+    fn test_repl_errors_keep_going() {
+        let output = run_with_stdin(
+            &["repl", "--tz", "UTC", "--now", "2024-11-11T12:00:00Z"],
+            ":tz Mars/Base\n:locale xx\n:what\nnot a date\ntoday\n",
+        );
+        let text = stdout(&output);
+
+        assert!(text.contains("Error: Unknown time zone: \"Mars/Base\""));
+        assert!(text.contains("Error: Unknown locale \"xx\""));
+        assert!(text.contains("Error: Unknown command \":what\""));
+        assert!(text.contains("2024-11-11T12:00:00+00:00 (Monday)"));
+    }
+
+    const NOW: [&str; 4] = ["--now", "2024-11-11T12:00:00Z", "--tz", "UTC"];
+
+    fn run_pinned(args: &[&str]) -> Output {
+        run(&[&NOW[..], args].concat())
+    }
+
+    #[test]
+    fn test_expression_without_quotes() {
+        let output = run_pinned(&["tomorrow", "at", "5", "PM"]);
+        assert_eq!(stdout(&output), "2024-11-12T17:00:00+00:00");
+
+        let output = run_pinned(&["parse", "--format", "unix", "tomorrow", "at", "5", "PM"]);
+        assert_eq!(stdout(&output), "1731430800");
+    }
+
+    #[test]
+    fn test_range_command() {
+        let output = run_pinned(&["range", "next", "month"]);
+        assert_eq!(
+            stdout(&output),
+            "2024-12-01T00:00:00+00:00/2025-01-01T00:00:00+00:00"
+        );
+
+        let output = run_pinned(&["range", "--format", "json", "Q2"]);
+        let json: Value = serde_json::from_str(&stdout(&output)).unwrap();
+        assert_eq!(json["start"], "2024-04-01T00:00:00+00:00");
+        assert_eq!(json["end"], "2024-07-01T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_duration_command() {
+        let output = run_pinned(&["duration", "2", "hours", "and", "30", "minutes"]);
+        assert_eq!(stdout(&output), "PT2H30M");
+
+        let output = run_pinned(&["duration", "1 year, 2 weeks and 3 days"]);
+        assert_eq!(stdout(&output), "P1Y17D");
+
+        let output = run_pinned(&["duration", "--format", "seconds", "a", "week"]);
+        assert_eq!(stdout(&output), "604800");
+    }
+
+    #[test]
+    fn test_humanize_command() {
+        assert_eq!(stdout(&run_pinned(&["humanize", "in 3 days"])), "in 3 days");
+        assert_eq!(
+            stdout(&run_pinned(&["humanize", "2", "hours", "ago"])),
+            "2 hours ago"
+        );
+        assert_eq!(stdout(&run_pinned(&["humanize", "today"])), "now");
+        assert_eq!(
+            stdout(&run_pinned(&["humanize", "in 23 hours and 50 minutes"])),
+            "in 1 day"
+        );
+    }
+
+    #[test]
+    fn test_diff_command() {
+        let diff = |unit: &str| {
+            stdout(&run_pinned(&[
+                "diff",
+                "today",
+                "end of quarter",
+                "-u",
+                unit,
+            ]))
+        };
+        assert_eq!(diff("days"), "50");
+        assert_eq!(diff("business-days"), "36");
+        assert_eq!(diff("hours"), "1211");
+        assert_eq!(diff("weeks"), "7");
+        assert_eq!(diff("human"), "2 months");
+
+        // "next Friday" is the Friday of next week, November 22.
+        let output = run_pinned(&["between", "next Friday", "today"]);
+        assert_eq!(stdout(&output), "-11");
+
+        let output = run_pinned(&["diff", "today", "end", "of", "quarter"]);
+        assert_eq!(stdout(&output), "50");
+
+        let output = run_pinned(&["between", "today", "and", "next", "Friday"]);
+        assert_eq!(stdout(&output), "11");
+
+        let output = run_pinned(&[
+            "diff", "today", "to", "in", "2", "days", "and", "3", "hours", "-u", "hours",
+        ]);
+        assert_eq!(stdout(&output), "51");
+
+        let output = run_pinned(&["diff", "today", "not a date"]);
+        assert_eq!(output.status.code(), Some(1));
+        assert!(stderr(&output).contains("not a date"));
+    }
+
+    #[test]
+    fn test_occurrences_command() {
+        let output = run(&[
+            "--tz",
+            "UTC",
+            "occurrences",
+            "every 2nd Tuesday",
+            "--count",
+            "3",
+            "--after",
+            "2026-01-01",
+        ]);
+        assert_eq!(
+            stdout(&output),
+            "2026-01-13T00:00:00+00:00\n2026-02-10T00:00:00+00:00\n2026-03-10T00:00:00+00:00"
+        );
+
+        // --after is exclusive.
+        let output = run_pinned(&[
+            "occurrences",
+            "every day",
+            "--count",
+            "1",
+            "--after",
+            "2024-11-12T12:00:00Z",
+        ]);
+        assert_eq!(stdout(&output), "2024-11-13T12:00:00+00:00");
+    }
+
+    #[test]
+    fn test_occurrences_between() {
+        // The start is included and the end is not.
+        let output = run_pinned(&[
+            "occurrences",
+            "every Monday",
+            "--between",
+            "2024-11-04",
+            "2024-11-25",
+            "-o",
+            "date",
+        ]);
+        assert_eq!(stdout(&output), "2024-11-04\n2024-11-11\n2024-11-18");
+
+        let output = run_pinned(&[
+            "occurrences",
+            "every day",
+            "--between",
+            "today",
+            "in 2 weeks",
+            "--count",
+            "2",
+            "--format",
+            "unix",
+        ]);
+        assert_eq!(stdout(&output).lines().count(), 2);
+
+        let output = run_pinned(&[
+            "occurrences",
+            "every day",
+            "--between",
+            "2024-11-12",
+            "2024-11-11",
+        ]);
+        assert_eq!(output.status.code(), Some(2));
+
+        let output = run_pinned(&[
+            "occurrences",
+            "every day",
+            "--between",
+            "2024-11-12",
+            "--after",
+            "2024-11-11",
+        ]);
+        assert_eq!(output.status.code(), Some(2));
+    }
+
+    #[test]
+    fn test_recur_command() {
+        let output = run_pinned(&[
+            "recur", "--count", "3", "every", "other", "Friday", "at", "9am",
+        ]);
+        assert_eq!(
+            stdout(&output),
+            "2024-11-15T09:00:00+00:00\n2024-11-29T09:00:00+00:00\n2024-12-13T09:00:00+00:00"
+        );
+
+        let output = run_pinned(&["recur", "--count", "2", "--format", "json", "every day"]);
+        let objects: Vec<Value> = stdout(&output)
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(objects.len(), 2);
+        assert_eq!(objects[0]["datetime"], "2024-11-12T12:00:00+00:00");
+        assert_eq!(objects[1]["datetime"], "2024-11-13T12:00:00+00:00");
+        assert_eq!(objects[0]["input"], "every day");
+        assert_eq!(objects[0]["granularity"], "day");
+        assert_eq!(objects[0]["timestamp"], 1731412800);
+
+        let output = run_pinned(&[
+            "occurrences",
+            "--count",
+            "1",
+            "--format",
+            "json",
+            "every day at 9am EST",
+        ]);
+        let json: Value = serde_json::from_str(&stdout(&output)).unwrap();
+        assert_eq!(json["granularity"], "time");
+        assert_eq!(json["zone"]["source"], "EST");
+    }
+
+    #[test]
+    fn test_explain_command() {
+        let text = stdout(&run_pinned(&["explain", "tomorrow", "at", "5", "PM"]));
+
+        assert!(text.starts_with("2024-11-12T17:00:00+00:00 (Tuesday)"));
+        assert!(text.contains("  relative_day_and_specific_time 0..16 \"tomorrow at 5 PM\""));
+        assert!(text.contains(
+            "step: reference_time ParserConfig::now -> 2024-11-11T12:00:00+00:00 (Monday)\n\
+             step: relative_term \"tomorrow\" -> 2024-11-12T12:00:00+00:00 (Tuesday)\n\
+             step: process_time_of_day 2024-11-12 17:00:00 -> 2024-11-12T17:00:00+00:00 (Tuesday)\n\
+             step: relative_day_and_specific_time \"tomorrow at 5 PM\" -> 2024-11-12T17:00:00+00:00 (Tuesday)"
+        ));
+    }
+
+    #[test]
+    fn test_explain_shows_weekday_shift() {
+        let text = stdout(&run(&[
+            "--now",
+            "2024-11-10T12:00:00Z",
+            "--tz",
+            "UTC",
+            "explain",
+            "next",
+            "Sunday",
+        ]));
+
+        assert!(text.contains(
+            "step: shift_to_weekday next Sun from Sun: +14 days -> 2024-11-24T12:00:00+00:00 (Sunday)"
+        ));
+    }
+
+    #[test]
+    fn test_help_per_command() {
+        for command in [
+            "parse",
+            "range",
+            "duration",
+            "humanize",
+            "diff",
+            "occurrences",
+            "explain",
+        ] {
+            let output = run(&[command, "--help"]);
+            assert!(output.status.success());
+            assert!(stdout(&output).contains(&format!("natural-date-parser {}", command)));
+        }
+
+        let output = run(&["recur", "--help"]);
+        assert!(output.status.success());
+        assert!(stdout(&output).contains("natural-date-parser occurrences"));
+    }
+
+    #[test]
+    fn test_exit_codes() {
+        assert_eq!(run_pinned(&["today"]).status.code(), Some(0));
+
+        assert_eq!(run_pinned(&["not a date"]).status.code(), Some(1));
+        assert_eq!(
+            run_pinned(&["range", "yesterday at noon"]).status.code(),
+            Some(1)
+        );
+        assert_eq!(
+            run_pinned(&["recur", "every 0 days"]).status.code(),
+            Some(1)
+        );
+        assert_eq!(
+            run_with_stdin(&["--batch", "--continue-on-error"], "today\nnot a date")
+                .status
+                .code(),
+            Some(1)
+        );
+
+        assert_eq!(run(&["--format", "yaml", "today"]).status.code(), Some(2));
+        assert_eq!(run(&["recur", "--count"]).status.code(), Some(2));
+        assert_eq!(run(&["--tz", "Mars/Base", "today"]).status.code(), Some(2));
+        assert_eq!(
+            run(&["--now", "not a time", "today"]).status.code(),
+            Some(2)
+        );
+        assert_eq!(
+            run(&["--format", "custom", "--pattern", "%Q", "today"])
+                .status
+                .code(),
+            Some(2)
+        );
+
+        assert_eq!(
+            run(&["--input", "/nonexistent/file.txt"]).status.code(),
+            Some(3)
+        );
+    }
+
+    #[test]
+    fn test_output_pattern_and_presets() {
+        let cases = [
+            ("%Y-%m-%d", "2024-11-22"),
+            ("%d.%m.%Y %H:%M", "22.11.2024 17:00"),
+            ("date", "2024-11-22"),
+            ("time", "17:00:00"),
+            ("iso", "2024-11-22T17:00:00+00:00"),
+            ("rfc2822", "Fri, 22 Nov 2024 17:00:00 +0000"),
+            ("epoch-ms", "1732294800000"),
+        ];
+        for (output, expected) in cases {
+            let result = run_pinned(&["next", "friday", "at", "5pm", "--output", output]);
+            assert_eq!(stdout(&result), expected, "--output {}", output);
+        }
+    }
+
+    #[test]
+    fn test_output_in_other_commands() {
+        assert_eq!(
+            stdout(&run_pinned(&["range", "-o", "date", "next", "week"])),
+            "2024-11-18/2024-11-25"
+        );
+        assert_eq!(
+            stdout(&run_pinned(&[
+                "recur",
+                "--count",
+                "2",
+                "-o",
+                "%a %d",
+                "every day"
+            ])),
+            "Tue 12\nWed 13"
+        );
+        assert_eq!(
+            stdout(&run_with_stdin(
+                &[&NOW[..], &["--batch", "-o", "date"]].concat(),
+                "today\ntomorrow\n"
+            )),
+            "2024-11-11\n2024-11-12"
+        );
+    }
+
+    #[test]
+    fn test_to_tz() {
+        assert_eq!(
+            stdout(&run_pinned(&["--to-tz", "Asia/Tokyo", "tomorrow at 5pm"])),
+            "2024-11-13T02:00:00+09:00"
+        );
+        assert_eq!(
+            stdout(&run_pinned(&[
+                "tomorrow",
+                "at",
+                "5pm",
+                "--to-tz",
+                "America/New_York",
+                "-o",
+                "%Y-%m-%d %H:%M %Z"
+            ])),
+            "2024-11-12 12:00 -05:00"
+        );
+        assert_eq!(
+            stdout(&run_pinned(&["--to-tz", "Asia/Tokyo", "range", "Q2"])),
+            "2024-04-01T09:00:00+09:00/2024-07-01T09:00:00+09:00"
+        );
+    }
+
+    #[test]
+    fn test_invalid_output_and_to_tz() {
+        assert_eq!(run_pinned(&["today", "-o", "datee"]).status.code(), Some(2));
+        assert_eq!(run_pinned(&["today", "-o", "%Q"]).status.code(), Some(2));
+        assert_eq!(
+            run_pinned(&["today", "-o", "date", "--format", "unix"])
+                .status
+                .code(),
+            Some(2)
+        );
+        assert_eq!(
+            run_pinned(&["today", "--to-tz", "Mars/Base"]).status.code(),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_completions() {
+        for shell in ["bash", "zsh", "fish"] {
+            let output = run(&["completions", shell]);
+            assert!(output.status.success(), "{}", stderr(&output));
+            let script = stdout(&output);
+            for command in ["occurrences", "diff", "completions", "to-tz"] {
+                assert!(
+                    script.contains(command),
+                    "{} script lacks {}",
+                    shell,
+                    command
+                );
+            }
+        }
+
+        let script = stdout(&run(&["completions", "zsh"]));
+        assert!(script.starts_with("#compdef natural-date-parser"));
+        let script = stdout(&run(&["completions", "fish"]));
+        assert!(script.contains("complete -c natural-date-parser"));
+
+        // The bash script is checked for syntax errors without running it.
+        let output = run(&["completions", "bash"]);
+        let mut bash = Command::new("bash")
+            .arg("-n")
+            .stdin(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .unwrap();
+        bash.stdin
+            .take()
+            .unwrap()
+            .write_all(&output.stdout)
+            .unwrap();
+        let checked = bash.wait_with_output().unwrap();
+        assert!(checked.status.success(), "{}", stderr(&checked));
+
+        assert_eq!(run(&["completions", "tcsh"]).status.code(), Some(2));
+    }
+
+    #[test]
+    fn test_man_page() {
+        let page = stdout(&run(&["man"]));
+        assert!(page.contains(".TH natural-date-parser 1"));
+        for section in [".SH NAME", ".SH SYNOPSIS", ".SH OPTIONS", ".SH SUBCOMMANDS"] {
+            assert!(page.contains(section), "man page lacks {}", section);
+        }
+        // Every control line starts with the name of a request or macro.
+        for line in page.lines().filter(|line| line.starts_with('.')) {
+            let name = line[1..].split_whitespace().next().unwrap_or("");
+            assert!(
+                !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric()),
+                "Malformed roff line: {}",
+                line
+            );
+        }
+
+        let page = stdout(&run(&["man", "occurrences"]));
+        assert!(page.contains(".TH natural-date-parser-occurrences 1"));
+        assert!(page.contains("between"));
+        assert_eq!(run(&["man", "nope"]).status.code(), Some(2));
+    }
+}
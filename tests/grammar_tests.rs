@@ -1,8 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use anyhow::{anyhow, Result};
+    use anyhow::{Result, anyhow};
     use natural_date_parser::{DateParser, Rule};
     use pest::Parser;
+    use pest::error::ErrorVariant;
 
     fn parse_rule(rule: Rule, input: &str) -> Result<()> {
         DateParser::parse(rule, input)
@@ -103,9 +104,437 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_weekday_of_month() -> Result<()> {
+        let expressions = [
+            "the second Tuesday of March",
+            "first monday of next month",
+            "Last Friday of the month",
+            "the fifth Sunday of this month",
+            "the third Wednesday of October 2026",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::weekday_of_month, expr)?;
+        }
+
+        let invalid_cases = [
+            "sixth Monday of March",
+            "the second Tuesday",
+            "second of March",
+        ];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_period_boundary() -> Result<()> {
+        let expressions = [
+            "end of month",
+            "the end of the quarter",
+            "start of next week",
+            "beginning of Q3",
+            "middle of last year",
+            "mid of the day",
+            "end of March",
+            "start of 2026",
+            "end of tomorrow",
+            "EOD",
+            "eow",
+            "EOM",
+            "EOQ",
+            "EOY",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::period_boundary, expr)?;
+        }
+
+        let invalid_cases = ["end of Q5", "end of", "start next week", "EOM 2"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_expression() -> Result<()> {
+        let expressions = [
+            "Q2",
+            "q4 2025",
+            "Q1 FY26",
+            "FY2026",
+            "FY 2026",
+            "this FY",
+            "next fiscal quarter",
+            "last quarter",
+            "next fiscal year",
+            "this month",
+            "March 2026",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::range_expression, expr)?;
+        }
+
+        let invalid_cases = ["Q0", "FY202", "next 2 quarters", "fiscal Q2"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::range_expression, case).is_err(),
+                "Parsed invalid range '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_iso_weeks() -> Result<()> {
+        let ranges = [
+            "week 42",
+            "Week 7 of 2025",
+            "2025-W07",
+            "2025W07",
+            "2025-W07-3",
+        ];
+        for range in ranges {
+            parse_rule(Rule::range_expression, range)?;
+        }
+
+        let expressions = [
+            "2025-W07-3",
+            "2025W073",
+            "next week's Monday",
+            "Friday of this week",
+            "Tuesday of week 42",
+            "next week's Friday at 5 PM",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::date_expression, expr)?;
+        }
+
+        let invalid_cases = ["week of 2025", "2025-W7", "2025-W07-8", "week 420"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::range_expression, case).is_err(),
+                "Parsed invalid week '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_holiday() -> Result<()> {
+        let expressions = [
+            "Christmas",
+            "Easter Monday",
+            "next Thanksgiving",
+            "New Year's Day 2026",
+            "Martin Luther King Jr. Day",
+            "Christmas Eve at 6 PM",
+            "end of Christmas",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::date_expression, expr)?;
+        }
+
+        let invalid_cases = ["Christmas at", "next", "Easter of 2026"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_named_day_keywords() -> Result<()> {
+        for name in ["May Day", "Boxing Day", "Valentine's Day", "payday"] {
+            parse_rule(Rule::named_day, name)?;
+        }
+
+        let invalid_cases = [
+            "every day",
+            "week 53",
+            "tomorrow",
+            "two days",
+            "Q4",
+            "FY2026",
+        ];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::named_day, case).is_err(),
+                "Parsed keyword '{}' as a day name",
+                case
+            );
+        }
+
+        // Malformed expressions are not reported as a name followed by junk.
+        for case in ["Q4", "every 0 days", "week 53 2020"] {
+            let error = DateParser::parse(Rule::date_expression, case).unwrap_err();
+            match error.variant {
+                ErrorVariant::ParsingError { positives, .. } => {
+                    assert!(
+                        !positives.contains(&Rule::time_zone),
+                        "Expected a time zone after '{}'",
+                        case
+                    );
+                    assert!(!positives.contains(&Rule::year_number));
+                }
+                ErrorVariant::CustomError { message } => panic!("{}", message),
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_vocabulary_words() -> Result<()> {
+        let expressions = [
+            "payday",
+            "next payday",
+            "sprint end 2026",
+            "tomorrow at standup",
+            "Friday at noon",
+            "in 2 sprints",
+            "a fortnight ago",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::date_expression, expr)?;
+        }
+
+        let invalid_cases = ["tomorrow at the standup", "in sprints", "in 2 next"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_time_zones() -> Result<()> {
+        let expressions = [
+            "3pm EST",
+            "3 PM PT",
+            "9:00 UTC+2",
+            "17:30 GMT-05:30",
+            "10 AM Europe/Kyiv",
+            "noon in Tokyo",
+            "tomorrow at 3pm PT",
+            "next Friday at noon in New York",
+            "Monday at 9:00 America/Argentina/Buenos_Aires",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::date_expression, expr)?;
+        }
+
+        let invalid_cases = ["3pm UTC+", "3pm in", "9:00 UTC+123", "EST 3pm"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        assert!(parse_rule(Rule::time_expression, "noon").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_anchored_date() -> Result<()> {
+        let expressions = [
+            "2 days after next Monday",
+            "the Friday before Christmas",
+            "Friday after Easter 2026",
+            "the day after Thanksgiving",
+            "3 business days before the end of the month",
+            "a week and 2 days before New Year's Day",
+            "the Monday after 2 weeks after Easter",
+            "2 hours after tomorrow at 3pm",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::date_expression, expr)?;
+        }
+
+        let invalid_cases = [
+            "Friday before",
+            "before Christmas",
+            "2 days after",
+            "the day before after Christmas",
+            "the 2 days after Christmas",
+        ];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ambiguous_expressions() -> Result<()> {
+        let expressions = [
+            "03/04",
+            "03/04/2025",
+            "3.4.25",
+            "13-04-2025",
+            "at 7",
+            "Friday at 7",
+            "03/04/2025 at 7 PM",
+            "tomorrow at 19",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::date_expression, expr)?;
+        }
+
+        let invalid_cases = ["03/04/202", "03/04/", "123/04", "03/04/2025/01", "at 7:"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_calendar_date() -> Result<()> {
+        let expressions = [
+            "March 3",
+            "March 3rd, 2026",
+            "march 3 2026",
+            "the 3rd of March",
+            "3 March 2026",
+            "March 3 at 5 PM",
+            "tomorrow noon",
+            "next Friday 5:30 PM",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::date_expression, expr)?;
+        }
+
+        let invalid_cases = ["March 123", "EOM 2", "Christmas 5:30 PM", "3rd"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_bound_expression() -> Result<()> {
+        let expressions = [
+            "by Friday",
+            "before tomorrow noon",
+            "until the end of the month",
+            "till 5 PM",
+            "no later than March 3",
+            "after Christmas",
+            "since last Monday",
+            "from next week's Monday",
+            "No earlier than 03/04/2025 at 9 AM",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::bound_expression, expr)?;
+        }
+
+        let invalid_cases = ["Friday", "by", "by before Friday", "no later Friday"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::bound_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_fuzzy_date() -> Result<()> {
+        let expressions = [
+            "around 3pm",
+            "about next Friday",
+            "roughly tomorrow at noon",
+            "approximately 2 days after Christmas",
+            "early next week",
+            "late March",
+            "mid-2026",
+            "mid Q3",
+            "late tomorrow",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::date_expression, expr)?;
+        }
+
+        let invalid_cases = [
+            "around",
+            "late",
+            "around around 3pm",
+            "2 days after around Friday",
+            "early-",
+        ];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_business_days() -> Result<()> {
+        let expressions = [
+            "in 3 business days",
+            "5 workdays ago",
+            "in a working day",
+            "next working day",
+            "the previous business day",
+            "next business day at 9 AM",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::date_expression, expr)?;
+        }
+
+        let units = [
+            "business day",
+            "business days",
+            "working days",
+            "workday",
+            "workdays",
+        ];
+        for unit in units {
+            parse_rule(Rule::business_day_s, unit)?;
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_relative_day_and_specific_time() -> Result<()> {
-        let expressions = ["next Monday at 10:30AM", "yesterday at 5:15pm"];
+        let expressions = [
+            "next Monday at 10:30AM",
+            "yesterday at 5:15pm",
+            "the first Monday of next month at 9 AM",
+        ];
         for expr in expressions {
             parse_rule(Rule::relative_day_and_specific_time, expr)?;
         }
@@ -114,17 +543,119 @@ mod tests {
 
     #[test]
     fn test_future_time() -> Result<()> {
-        let times = ["in 2 days", "in 3 weeks", "in 1 month", "in 5 years"];
+        let times = [
+            "in 2 days",
+            "in 3 weeks",
+            "in 1 month",
+            "in 5 years",
+            "in 2 weeks and 3 days",
+            "in 1 year 2 months",
+            "in 1 year, 2 months and 3 days",
+        ];
         for time in times {
             parse_rule(Rule::future_time, time)?;
         }
         Ok(())
     }
 
+    #[test]
+    fn test_duration() -> Result<()> {
+        let durations = [
+            "3 days",
+            "2 weeks and 3 days",
+            "1 year 2 months",
+            "1 year, 2 months, and 3 days",
+        ];
+        for duration in durations {
+            parse_rule(Rule::duration, duration)?;
+        }
+
+        let invalid_cases = ["and 3 days", "2 weeks and", "2 3 days"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, &format!("in {}", case)).is_err(),
+                "Parsed invalid duration '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_recurrence_expression() -> Result<()> {
+        let expressions = [
+            "every day",
+            "every 2 weeks",
+            "every other Friday",
+            "Every Monday at 9am",
+            "every business day at 17:30",
+            "every three months",
+            "every other week",
+            "every 2nd Tuesday",
+            "every second Tuesday of the month",
+            "every last Friday of month",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::recurrence_expression, expr)?;
+        }
+
+        let invalid_cases = ["every", "Monday", "every 2", "every other", "every day at"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::recurrence_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_past_time() -> Result<()> {
+        let times = [
+            "3 days ago",
+            "twenty-five minutes ago",
+            "a couple of weeks ago",
+            "half an hour ago",
+            "1 year and 2 months ago",
+        ];
+        for time in times {
+            parse_rule(Rule::past_time, time)?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_spelled_out_numbers_in_date_expression() -> Result<()> {
+        let expressions = [
+            "in three days",
+            "in a week",
+            "in an hour",
+            "in a couple of weeks",
+            "in a few days and twelve hours",
+            "in a dozen years",
+            "in one hundred days",
+        ];
+        for expr in expressions {
+            parse_rule(Rule::date_expression, expr)?;
+        }
+
+        let invalid_cases = ["in threee days", "in twenty-fivedays", "in sevenn weeks"];
+        for case in invalid_cases {
+            assert!(
+                parse_rule(Rule::date_expression, case).is_err(),
+                "Parsed invalid expression '{}'",
+                case
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_time_unit() -> Result<()> {
         let units = [
-            "day", "days", "week", "weeks", "month", "months", "year", "years",
+            "minute", "minutes", "hour", "hours", "day", "days", "week", "weeks", "month",
+            "months", "year", "years",
         ];
         for unit in units {
             parse_rule(Rule::time_unit, unit)?;
@@ -224,7 +755,25 @@ mod tests {
 
     #[test]
     fn test_number() -> Result<()> {
-        let valid_cases = vec!["1", "10", "999", "42"];
+        let valid_cases = vec![
+            "1",
+            "10",
+            "999",
+            "42",
+            "three",
+            "Seventeen",
+            "twenty-five",
+            "ninety nine",
+            "a hundred",
+            "two hundred and five",
+            "a",
+            "an",
+            "a couple of",
+            "a few",
+            "several",
+            "a dozen",
+            "half an",
+        ];
         for case in &valid_cases {
             parse_rule(Rule::number, case)
                 .map_err(|e| anyhow!("Failed to parse valid number '{}': {}", case, e))?;
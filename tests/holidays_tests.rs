@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Weekday};
+    use natural_date_parser::holidays::{
+        HolidayCalendar, HolidayProvider, HolidayRule, easter_sunday, normalize_name,
+    };
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_easter_sunday() {
+        assert_eq!(easter_sunday(2000), Some(date(2000, 4, 23)));
+        assert_eq!(easter_sunday(2019), Some(date(2019, 4, 21)));
+        assert_eq!(easter_sunday(2024), Some(date(2024, 3, 31)));
+        assert_eq!(easter_sunday(2025), Some(date(2025, 4, 20)));
+        assert_eq!(easter_sunday(2026), Some(date(2026, 4, 5)));
+        assert_eq!(easter_sunday(2038), Some(date(2038, 4, 25)));
+    }
+
+    #[test]
+    fn test_holiday_rules() {
+        let fixed = HolidayRule::Fixed { month: 12, day: 25 };
+        assert_eq!(fixed.date_in(2024), Some(date(2024, 12, 25)));
+
+        let leap_day = HolidayRule::Fixed { month: 2, day: 29 };
+        assert_eq!(leap_day.date_in(2025), None);
+
+        let fourth_thursday = HolidayRule::NthWeekday {
+            month: 11,
+            weekday: Weekday::Thu,
+            n: 4,
+        };
+        assert_eq!(fourth_thursday.date_in(2024), Some(date(2024, 11, 28)));
+
+        let last_monday = HolidayRule::NthWeekday {
+            month: 5,
+            weekday: Weekday::Mon,
+            n: -1,
+        };
+        assert_eq!(last_monday.date_in(2024), Some(date(2024, 5, 27)));
+
+        let second_to_last_monday = HolidayRule::NthWeekday {
+            month: 5,
+            weekday: Weekday::Mon,
+            n: -2,
+        };
+        assert_eq!(second_to_last_monday.date_in(2024), Some(date(2024, 5, 20)));
+
+        let out_of_month = HolidayRule::NthWeekday {
+            month: 5,
+            weekday: Weekday::Mon,
+            n: i8::MIN,
+        };
+        assert_eq!(out_of_month.date_in(2024), None);
+
+        let easter_monday = HolidayRule::EasterOffset(1);
+        assert_eq!(easter_monday.date_in(2025), Some(date(2025, 4, 21)));
+    }
+
+    #[test]
+    fn test_united_states_calendar() {
+        let calendar = HolidayCalendar::united_states();
+
+        assert_eq!(
+            calendar.date_of("Christmas", 2024),
+            Some(date(2024, 12, 25))
+        );
+        assert_eq!(calendar.date_of("xmas", 2024), Some(date(2024, 12, 25)));
+        assert_eq!(
+            calendar.date_of("thanksgiving", 2024),
+            Some(date(2024, 11, 28))
+        );
+        assert_eq!(
+            calendar.date_of("New Years Day", 2025),
+            Some(date(2025, 1, 1))
+        );
+        assert_eq!(
+            calendar.date_of("Good Friday", 2025),
+            Some(date(2025, 4, 18))
+        );
+        assert_eq!(calendar.date_of("Boxing Day", 2025), None);
+
+        assert!(calendar.is_holiday(date(2024, 7, 4)));
+        assert!(!calendar.is_holiday(date(2024, 7, 5)));
+    }
+
+    #[test]
+    fn test_united_kingdom_calendar() {
+        let calendar = HolidayCalendar::united_kingdom();
+
+        assert_eq!(
+            calendar.date_of("Boxing Day", 2025),
+            Some(date(2025, 12, 26))
+        );
+        assert_eq!(
+            calendar.date_of("summer bank holiday", 2025),
+            Some(date(2025, 8, 25))
+        );
+        assert_eq!(calendar.date_of("Thanksgiving", 2025), None);
+    }
+
+    #[test]
+    fn test_custom_calendar() {
+        let mut calendar = HolidayCalendar::new();
+        calendar.add(
+            &["Company Day", "Founders Day"],
+            HolidayRule::Fixed { month: 6, day: 1 },
+        );
+
+        assert_eq!(
+            calendar.date_of("founders day", 2026),
+            Some(date(2026, 6, 1))
+        );
+        assert_eq!(
+            calendar.holidays_in(2026),
+            vec![("Company Day".to_string(), date(2026, 6, 1))]
+        );
+    }
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("New Year's  Day"), "new years day");
+        assert_eq!(
+            normalize_name("Martin Luther King Jr. Day"),
+            "martin luther king jr day"
+        );
+    }
+}
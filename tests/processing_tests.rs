@@ -1,9 +1,39 @@
 #[cfg(test)]
 mod tests {
     mod helping_functions {
-        use chrono::{DateTime, Weekday};
-        use chrono::{Datelike, Local};
-        use natural_date_parser::ParseDateError;
+        use chrono::Datelike;
+        use chrono::{DateTime, NaiveDate, TimeZone, Weekday};
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{ParseDateError, ParserConfig};
+
+        pub(super) fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+            NaiveDate::from_ymd_opt(year, month, day).unwrap()
+        }
+
+        pub(super) fn at(
+            year: i32,
+            month: u32,
+            day: u32,
+            hour: u32,
+            minute: u32,
+            second: u32,
+        ) -> DateTime<ReferenceZone> {
+            ReferenceZone::Local
+                .with_ymd_and_hms(year, month, day, hour, minute, second)
+                .unwrap()
+        }
+
+        pub(super) fn pinned() -> ParserConfig {
+            // Monday, November 11, 2024
+            pinned_at(at(2024, 11, 11, 12, 0, 0))
+        }
+
+        pub(super) fn pinned_at(now: DateTime<ReferenceZone>) -> ParserConfig {
+            ParserConfig {
+                now: Some(now.fixed_offset()),
+                ..Default::default()
+            }
+        }
 
         pub(super) fn assert_weekday_result(
             result: Result<Weekday, ParseDateError>,
@@ -16,7 +46,7 @@ mod tests {
         }
 
         pub(super) fn assert_specific_day_result(
-            result: Result<DateTime<Local>, ParseDateError>,
+            result: Result<DateTime<ReferenceZone>, ParseDateError>,
             expected_weekday: Weekday,
         ) {
             match result {
@@ -98,13 +128,14 @@ mod tests {
     #[cfg(test)]
     mod process_specific_day_tests {
         use super::helping_functions::assert_specific_day_result;
-        use chrono::{Local, Weekday};
+        use chrono::Weekday;
         use natural_date_parser::date_parser::process_specific_day;
+        use natural_date_parser::zones::ReferenceZone;
         use natural_date_parser::{ParseDateError, Rule};
 
         #[test]
         fn test_process_specific_day_valid() {
-            let datetime = Local::now();
+            let datetime = ReferenceZone::Local.now();
 
             let monday_rule = Rule::monday;
             let tuesday_rule = Rule::tuesday;
@@ -129,7 +160,7 @@ mod tests {
         #[test]
         fn test_process_specific_day_invalid() {
             let invalid_rule = Rule::EOI;
-            let datetime = Local::now();
+            let datetime = ReferenceZone::Local.now();
 
             let result = process_specific_day(invalid_rule, datetime);
 
@@ -143,7 +174,7 @@ mod tests {
 
         #[test]
         fn test_process_specific_day_with_future_weekday() {
-            let datetime = Local::now();
+            let datetime = ReferenceZone::Local.now();
             let next_monday_rule = Rule::monday;
             let result = process_specific_day(next_monday_rule, datetime);
 
@@ -153,14 +184,17 @@ mod tests {
 
     #[cfg(test)]
     mod process_specific_time_tests {
-        use chrono::{DateTime, Local, TimeZone, Timelike};
+        use chrono::{DateTime, TimeZone, Timelike};
         use natural_date_parser::date_parser::process_specific_time;
+        use natural_date_parser::zones::ReferenceZone;
         use natural_date_parser::{DateParser, ParseDateError, Rule};
-        use pest::iterators::Pair;
         use pest::Parser;
+        use pest::iterators::Pair;
 
-        fn get_test_datetime() -> DateTime<Local> {
-            Local.with_ymd_and_hms(2024, 11, 11, 12, 0, 0).unwrap()
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
         }
 
         fn parse_input(input: &str) -> Result<Pair<'_, Rule>, pest::error::Error<Rule>> {
@@ -252,18 +286,19 @@ mod tests {
     #[cfg(test)]
     mod process_relative_term_tests {
         use chrono::DateTime;
-        use chrono::{Datelike, Duration, Local};
+        use chrono::{Datelike, Duration};
         use natural_date_parser::date_parser::process_relative_term;
+        use natural_date_parser::zones::ReferenceZone;
         use natural_date_parser::{DateParser, Rule};
         use pest::Parser;
 
-        fn test_relative_term_rule(input: &str, expected_datetime: DateTime<Local>) {
+        fn test_relative_term_rule(input: &str, expected_datetime: DateTime<ReferenceZone>) {
             let pair = DateParser::parse(Rule::relative_term, input)
                 .unwrap()
                 .next()
                 .unwrap();
 
-            let result = process_relative_term(pair);
+            let result = process_relative_term(pair, ReferenceZone::Local.now());
             assert!(result.is_ok());
             assert_eq!(result.as_ref().unwrap().year(), expected_datetime.year());
             assert_eq!(result.as_ref().unwrap().month(), expected_datetime.month());
@@ -272,33 +307,34 @@ mod tests {
 
         #[test]
         fn test_process_relative_term_tomorrow() {
-            let tomorrow = Local::now() + Duration::days(1);
+            let tomorrow = ReferenceZone::Local.now() + Duration::days(1);
             test_relative_term_rule("tomorrow", tomorrow);
         }
 
         #[test]
         fn test_process_relative_term_today() {
-            let today = Local::now();
+            let today = ReferenceZone::Local.now();
             test_relative_term_rule("today", today);
         }
 
         #[test]
         fn test_process_relative_term_yesterday() {
-            let yesterday = Local::now() - Duration::days(1);
+            let yesterday = ReferenceZone::Local.now() - Duration::days(1);
             test_relative_term_rule("yesterday", yesterday);
         }
     }
 
     #[cfg(test)]
     mod process_relative_date_tests {
-        use chrono::{Datelike, Duration, Local};
+        use chrono::{Datelike, Duration};
         use natural_date_parser::date_parser::process_relative_date;
+        use natural_date_parser::zones::ReferenceZone;
         use natural_date_parser::{DateParser, Rule};
         use pest::Parser;
 
         #[test]
         fn test_process_relative_date_next_monday() {
-            let today_weekday = Local::now().weekday();
+            let today_weekday = ReferenceZone::Local.now().weekday();
             let target_weekday = chrono::Weekday::Mon;
             let days_offset =
                 if today_weekday.num_days_from_monday() <= target_weekday.num_days_from_monday() {
@@ -318,8 +354,2266 @@ mod tests {
             println!("res {:#?}", result);
             assert!(result.is_ok());
 
-            let expected_date = Local::now() + Duration::days(days_offset);
+            let expected_date = ReferenceZone::Local.now() + Duration::days(days_offset);
             assert_eq!(result.unwrap().date_naive(), expected_date.date_naive());
         }
     }
+
+    #[cfg(test)]
+    mod process_future_time_tests {
+        use super::helping_functions::date;
+        use chrono::{DateTime, NaiveDate, TimeZone};
+        use natural_date_parser::date_parser::{process_duration, process_future_time};
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, ParserConfig, Rule};
+        use pest::Parser;
+
+        fn get_test_datetime(year: i32, month: u32, day: u32) -> DateTime<ReferenceZone> {
+            ReferenceZone::Local
+                .with_ymd_and_hms(year, month, day, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn future_date(input: &str, datetime: DateTime<ReferenceZone>) -> NaiveDate {
+            let pair = DateParser::parse(Rule::future_time, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_future_time(pair, datetime, &ParserConfig::default())
+                .unwrap()
+                .datetime
+                .date_naive()
+        }
+
+        #[test]
+        fn test_process_future_time_single_unit() {
+            let datetime = get_test_datetime(2024, 11, 11);
+
+            assert_eq!(future_date("in 2 days", datetime), date(2024, 11, 13));
+            assert_eq!(future_date("in 3 weeks", datetime), date(2024, 12, 2));
+            assert_eq!(future_date("in 1 month", datetime), date(2024, 12, 11));
+            assert_eq!(future_date("in 5 years", datetime), date(2029, 11, 11));
+        }
+
+        #[test]
+        fn test_process_future_time_compound() {
+            let datetime = get_test_datetime(2024, 11, 11);
+
+            assert_eq!(
+                future_date("in 2 weeks and 3 days", datetime),
+                date(2024, 11, 28)
+            );
+            assert_eq!(
+                future_date("in 1 year 2 months", datetime),
+                date(2026, 1, 11)
+            );
+            assert_eq!(
+                future_date("in 1 year, 2 months and 3 days", datetime),
+                date(2026, 1, 14)
+            );
+        }
+
+        #[test]
+        fn test_process_future_time_applies_months_before_days() {
+            // January 30 + 1 month is clamped to February 28 before the days are added.
+            let datetime = get_test_datetime(2025, 1, 30);
+
+            assert_eq!(
+                future_date("in 1 month and 2 days", datetime),
+                date(2025, 3, 2)
+            );
+            assert_eq!(
+                future_date("in 2 days and 1 month", datetime),
+                date(2025, 3, 2)
+            );
+        }
+
+        #[test]
+        fn test_process_future_time_combines_years_and_months() {
+            // Years are applied as twelve months, so February 29 clamps instead of failing.
+            let datetime = get_test_datetime(2024, 2, 29);
+
+            assert_eq!(future_date("in 1 year", datetime), date(2025, 2, 28));
+            assert_eq!(
+                future_date("in 1 month 1 month", datetime),
+                date(2024, 4, 29)
+            );
+        }
+
+        #[test]
+        fn test_process_future_time_out_of_range() {
+            let datetime = get_test_datetime(2024, 11, 11);
+            let config = ParserConfig::default();
+
+            for unit in ["weeks", "days"] {
+                let input = format!(
+                    "in {}",
+                    vec![format!("2147483647 {}", unit); 60].join(" and ")
+                );
+                let pair = DateParser::parse(Rule::future_time, &input)
+                    .unwrap()
+                    .next()
+                    .unwrap();
+
+                assert!(process_future_time(pair, datetime, &config).is_err());
+            }
+        }
+
+        #[test]
+        fn test_process_duration_keeps_written_order() {
+            let pair = DateParser::parse(Rule::duration, "3 days and 2 weeks")
+                .unwrap()
+                .next()
+                .unwrap();
+
+            let offsets = process_duration(pair, &ParserConfig::default()).unwrap();
+            assert_eq!(offsets, vec![(3, Rule::day_s), (2, Rule::week_s)]);
+        }
+    }
+
+    #[cfg(test)]
+    mod process_number_tests {
+        use chrono::{DateTime, Duration, TimeZone};
+        use natural_date_parser::date_parser::{cardinal_value, process_number, process_past_time};
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, InformalQuantities, ParserConfig, Rule};
+        use pest::Parser;
+
+        fn number(input: &str, config: &ParserConfig) -> i32 {
+            let pair = DateParser::parse(Rule::number, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_number(pair, config).unwrap()
+        }
+
+        fn past_time(input: &str, datetime: DateTime<ReferenceZone>) -> DateTime<ReferenceZone> {
+            let pair = DateParser::parse(Rule::past_time, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_past_time(pair, datetime, &ParserConfig::default())
+                .unwrap()
+                .datetime
+        }
+
+        #[test]
+        fn test_process_number_words() {
+            let config = ParserConfig::default();
+
+            assert_eq!(number("42", &config), 42);
+            assert_eq!(number("zero", &config), 0);
+            assert_eq!(number("three", &config), 3);
+            assert_eq!(number("Fifteen", &config), 15);
+            assert_eq!(number("forty", &config), 40);
+            assert_eq!(number("twenty-five", &config), 25);
+            assert_eq!(number("ninety nine", &config), 99);
+            assert_eq!(number("a hundred", &config), 100);
+            assert_eq!(number("two hundred and five", &config), 205);
+            assert_eq!(number("an", &config), 1);
+        }
+
+        #[test]
+        fn test_process_number_informal_quantities() {
+            let config = ParserConfig::default();
+
+            assert_eq!(number("a couple of", &config), 2);
+            assert_eq!(number("a few", &config), 3);
+            assert_eq!(number("several", &config), 5);
+            assert_eq!(number("a dozen", &config), 12);
+        }
+
+        #[test]
+        fn test_process_number_configured_quantities() {
+            let config = ParserConfig {
+                quantities: InformalQuantities {
+                    a_few: 4,
+                    several: 7,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            assert_eq!(number("a few", &config), 4);
+            assert_eq!(number("several", &config), 7);
+            assert_eq!(number("a couple", &config), 2);
+        }
+
+        #[test]
+        fn test_cardinal_value_invalid() {
+            assert_eq!(cardinal_value("twenty-five"), Some(25));
+            assert_eq!(cardinal_value("twenty-fivish"), None);
+        }
+
+        #[test]
+        fn test_process_past_time() {
+            let datetime = ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap();
+
+            assert_eq!(
+                past_time("twenty-five minutes ago", datetime),
+                datetime - Duration::minutes(25)
+            );
+            assert_eq!(
+                past_time("half an hour ago", datetime),
+                datetime - Duration::minutes(30)
+            );
+            assert_eq!(
+                past_time("a couple of weeks ago", datetime),
+                datetime - Duration::weeks(2)
+            );
+            assert_eq!(
+                past_time("1 month and 3 days ago", datetime),
+                ReferenceZone::Local
+                    .with_ymd_and_hms(2024, 10, 8, 12, 0, 0)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod process_weekday_of_month_tests {
+        use super::helping_functions::date;
+        use chrono::{DateTime, TimeZone, Timelike};
+        use natural_date_parser::date_parser::process_weekday_of_month;
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, ParseDateError, ParserConfig, Rule};
+        use pest::Parser;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn weekday_of_month(input: &str) -> Result<DateTime<ReferenceZone>, ParseDateError> {
+            let pair = DateParser::parse(Rule::weekday_of_month, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_weekday_of_month(pair, get_test_datetime(), &ParserConfig::default())
+                .map(|resolved| resolved.datetime)
+        }
+
+        #[test]
+        fn test_process_weekday_of_month_named_month() {
+            let result = weekday_of_month("the second Tuesday of March").unwrap();
+            assert_eq!(result.date_naive(), date(2024, 3, 12));
+            assert_eq!(result.hour(), 12);
+
+            let result = weekday_of_month("first Sunday of September 2026").unwrap();
+            assert_eq!(result.date_naive(), date(2026, 9, 6));
+        }
+
+        #[test]
+        fn test_process_weekday_of_month_relative_month() {
+            let result = weekday_of_month("last Friday of the month").unwrap();
+            assert_eq!(result.date_naive(), date(2024, 11, 29));
+
+            let result = weekday_of_month("the first Monday of next month").unwrap();
+            assert_eq!(result.date_naive(), date(2024, 12, 2));
+
+            let result = weekday_of_month("the fourth Thursday of this month").unwrap();
+            assert_eq!(result.date_naive(), date(2024, 11, 28));
+
+            let result = weekday_of_month("last Sunday of last month").unwrap();
+            assert_eq!(result.date_naive(), date(2024, 10, 27));
+        }
+
+        #[test]
+        fn test_process_weekday_of_month_fifth_occurrence() {
+            let result = weekday_of_month("the fifth Friday of November").unwrap();
+            assert_eq!(result.date_naive(), date(2024, 11, 29));
+
+            let result = weekday_of_month("the fifth Monday of February");
+            assert!(result.is_err());
+            if let Err(ParseDateError::ParseError(msg)) = result {
+                assert!(msg.contains("There is no"));
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod process_period_boundary_tests {
+        use chrono::{DateTime, NaiveTime, TimeZone, Weekday};
+        use natural_date_parser::date_parser::process_period_boundary;
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, ParserConfig, Rule};
+        use pest::Parser;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn boundary(input: &str, config: &ParserConfig) -> DateTime<ReferenceZone> {
+            let pair = DateParser::parse(Rule::period_boundary, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_period_boundary(pair, get_test_datetime(), config)
+                .unwrap()
+                .datetime
+        }
+
+        fn local(
+            year: i32,
+            month: u32,
+            day: u32,
+            hour: u32,
+            min: u32,
+            sec: u32,
+        ) -> DateTime<ReferenceZone> {
+            ReferenceZone::Local
+                .with_ymd_and_hms(year, month, day, hour, min, sec)
+                .unwrap()
+        }
+
+        #[test]
+        fn test_process_period_boundary_relative_periods() {
+            let config = ParserConfig::default();
+
+            assert_eq!(
+                boundary("end of the month", &config),
+                local(2024, 11, 30, 23, 59, 59)
+            );
+            assert_eq!(
+                boundary("start of next week", &config),
+                local(2024, 11, 18, 0, 0, 0)
+            );
+            assert_eq!(
+                boundary("end of last year", &config),
+                local(2023, 12, 31, 23, 59, 59)
+            );
+            assert_eq!(
+                boundary("start of next quarter", &config),
+                local(2025, 1, 1, 0, 0, 0)
+            );
+            assert_eq!(
+                boundary("end of tomorrow", &config),
+                local(2024, 11, 12, 23, 59, 59)
+            );
+        }
+
+        #[test]
+        fn test_process_period_boundary_named_periods() {
+            let config = ParserConfig::default();
+
+            assert_eq!(
+                boundary("beginning of Q3", &config),
+                local(2024, 7, 1, 0, 0, 0)
+            );
+            assert_eq!(
+                boundary("end of March", &config),
+                local(2024, 3, 31, 23, 59, 59)
+            );
+            assert_eq!(
+                boundary("end of February 2025", &config),
+                local(2025, 2, 28, 23, 59, 59)
+            );
+            assert_eq!(
+                boundary("start of 2026", &config),
+                local(2026, 1, 1, 0, 0, 0)
+            );
+        }
+
+        #[test]
+        fn test_process_period_boundary_middle() {
+            let config = ParserConfig::default();
+
+            assert_eq!(
+                boundary("middle of the day", &config),
+                local(2024, 11, 11, 12, 0, 0)
+            );
+            assert_eq!(
+                boundary("middle of the week", &config),
+                local(2024, 11, 14, 12, 0, 0)
+            );
+            assert_eq!(
+                boundary("mid of next month", &config),
+                local(2024, 12, 16, 12, 0, 0)
+            );
+        }
+
+        #[test]
+        fn test_process_period_boundary_abbreviations() {
+            let config = ParserConfig::default();
+
+            assert_eq!(boundary("EOD", &config), local(2024, 11, 11, 23, 59, 59));
+            assert_eq!(boundary("EOW", &config), local(2024, 11, 17, 23, 59, 59));
+            assert_eq!(boundary("EOM", &config), local(2024, 11, 30, 23, 59, 59));
+            assert_eq!(boundary("EOQ", &config), local(2024, 12, 31, 23, 59, 59));
+            assert_eq!(boundary("EOY", &config), local(2024, 12, 31, 23, 59, 59));
+        }
+
+        #[test]
+        fn test_process_period_boundary_uses_config() {
+            let config = ParserConfig {
+                week_start: Weekday::Sun,
+                end_of_day: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+                ..Default::default()
+            };
+
+            assert_eq!(
+                boundary("start of the week", &config),
+                local(2024, 11, 10, 0, 0, 0)
+            );
+            assert_eq!(boundary("EOW", &config), local(2024, 11, 16, 17, 0, 0));
+            assert_eq!(
+                boundary("end of the quarter", &config),
+                local(2024, 12, 31, 17, 0, 0)
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod process_range_expression_tests {
+        use chrono::{DateTime, TimeZone};
+        use natural_date_parser::date_parser::{process_period_boundary, process_range_expression};
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, DateRange, ParseDateError, ParserConfig, Rule};
+        use pest::Parser;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn fiscal_april() -> ParserConfig {
+            ParserConfig {
+                fiscal_year_start: 4,
+                ..Default::default()
+            }
+        }
+
+        fn range(input: &str, config: &ParserConfig) -> Result<DateRange, ParseDateError> {
+            let pair = DateParser::parse(Rule::range_expression, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_range_expression(pair, get_test_datetime(), config)
+        }
+
+        fn days(start: (i32, u32, u32), end: (i32, u32, u32)) -> DateRange {
+            DateRange {
+                start: ReferenceZone::Local
+                    .with_ymd_and_hms(start.0, start.1, start.2, 0, 0, 0)
+                    .unwrap(),
+                end: ReferenceZone::Local
+                    .with_ymd_and_hms(end.0, end.1, end.2, 0, 0, 0)
+                    .unwrap(),
+            }
+        }
+
+        #[test]
+        fn test_process_range_expression_calendar_quarters() {
+            let config = ParserConfig::default();
+
+            assert_eq!(
+                range("Q2", &config).unwrap(),
+                days((2024, 4, 1), (2024, 7, 1))
+            );
+            assert_eq!(
+                range("next quarter", &config).unwrap(),
+                days((2025, 1, 1), (2025, 4, 1))
+            );
+            assert_eq!(
+                range("FY2026", &config).unwrap(),
+                days((2026, 1, 1), (2027, 1, 1))
+            );
+            assert_eq!(
+                range("this month", &config).unwrap(),
+                days((2024, 11, 1), (2024, 12, 1))
+            );
+        }
+
+        #[test]
+        fn test_process_range_expression_fiscal_year() {
+            let config = fiscal_april();
+
+            assert_eq!(
+                range("this FY", &config).unwrap(),
+                days((2024, 4, 1), (2025, 4, 1))
+            );
+            assert_eq!(
+                range("FY2026", &config).unwrap(),
+                days((2025, 4, 1), (2026, 4, 1))
+            );
+            assert_eq!(
+                range("last fiscal year", &config).unwrap(),
+                days((2023, 4, 1), (2024, 4, 1))
+            );
+        }
+
+        #[test]
+        fn test_process_range_expression_fiscal_quarters() {
+            let config = fiscal_april();
+
+            assert_eq!(
+                range("Q2", &config).unwrap(),
+                days((2024, 7, 1), (2024, 10, 1))
+            );
+            assert_eq!(
+                range("this quarter", &config).unwrap(),
+                days((2024, 10, 1), (2025, 1, 1))
+            );
+            assert_eq!(
+                range("next fiscal quarter", &config).unwrap(),
+                days((2025, 1, 1), (2025, 4, 1))
+            );
+            assert_eq!(
+                range("last quarter", &config).unwrap(),
+                days((2024, 7, 1), (2024, 10, 1))
+            );
+            assert_eq!(
+                range("Q1 FY26", &config).unwrap(),
+                days((2025, 4, 1), (2025, 7, 1))
+            );
+            assert_eq!(
+                range("Q4 2025", &config).unwrap(),
+                days((2025, 1, 1), (2025, 4, 1))
+            );
+        }
+
+        #[test]
+        fn test_process_range_expression_invalid_fiscal_start() {
+            let config = ParserConfig {
+                fiscal_year_start: 13,
+                ..Default::default()
+            };
+
+            assert!(range("this quarter", &config).is_err());
+        }
+
+        #[test]
+        fn test_date_range_contains() {
+            let q2 = range("Q2", &ParserConfig::default()).unwrap();
+
+            assert!(q2.contains(&q2.start));
+            assert!(!q2.contains(&q2.end));
+            assert!(!q2.contains(&get_test_datetime()));
+        }
+
+        #[test]
+        fn test_period_boundary_of_fiscal_year() {
+            let pair = DateParser::parse(Rule::period_boundary, "end of FY2026")
+                .unwrap()
+                .next()
+                .unwrap();
+
+            let result = process_period_boundary(pair, get_test_datetime(), &fiscal_april());
+            assert_eq!(
+                result.unwrap().datetime,
+                ReferenceZone::Local
+                    .with_ymd_and_hms(2026, 3, 31, 23, 59, 59)
+                    .unwrap()
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod process_week_tests {
+        use super::helping_functions::date;
+        use chrono::{DateTime, NaiveDate, TimeZone, Weekday};
+        use natural_date_parser::date_parser::{process_range_expression, process_weekday_in_week};
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, DateRange, ParseDateError, ParserConfig, Rule};
+        use pest::Parser;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024 (ISO week 46)
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn range(input: &str) -> Result<DateRange, ParseDateError> {
+            let pair = DateParser::parse(Rule::range_expression, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_range_expression(pair, get_test_datetime(), &ParserConfig::default())
+        }
+
+        fn weekday_in_week(input: &str, config: &ParserConfig) -> NaiveDate {
+            let pair = DateParser::parse(Rule::weekday_in_week, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_weekday_in_week(pair, get_test_datetime(), config)
+                .unwrap()
+                .datetime
+                .date_naive()
+        }
+
+        fn days(start: NaiveDate, end: NaiveDate) -> DateRange {
+            DateRange {
+                start: ReferenceZone::Local
+                    .from_local_datetime(&start.and_hms_opt(0, 0, 0).unwrap())
+                    .unwrap(),
+                end: ReferenceZone::Local
+                    .from_local_datetime(&end.and_hms_opt(0, 0, 0).unwrap())
+                    .unwrap(),
+            }
+        }
+
+        #[test]
+        fn test_week_numbers() {
+            assert_eq!(
+                range("week 42").unwrap(),
+                days(date(2024, 10, 14), date(2024, 10, 21))
+            );
+            assert_eq!(
+                range("week 7 of 2025").unwrap(),
+                days(date(2025, 2, 10), date(2025, 2, 17))
+            );
+            assert!(range("week 54").is_err());
+        }
+
+        #[test]
+        fn test_iso_week_dates() {
+            assert_eq!(
+                range("2025-W07").unwrap(),
+                days(date(2025, 2, 10), date(2025, 2, 17))
+            );
+            assert_eq!(
+                range("2025-W07-3").unwrap(),
+                days(date(2025, 2, 12), date(2025, 2, 13))
+            );
+            assert_eq!(
+                range("2020W537").unwrap(),
+                days(date(2021, 1, 3), date(2021, 1, 4))
+            );
+            assert!(range("2025-W53").is_err());
+        }
+
+        #[test]
+        fn test_weekday_in_week() {
+            let config = ParserConfig::default();
+
+            assert_eq!(
+                weekday_in_week("next week's Monday", &config),
+                date(2024, 11, 18)
+            );
+            assert_eq!(
+                weekday_in_week("Friday of this week", &config),
+                date(2024, 11, 15)
+            );
+            assert_eq!(
+                weekday_in_week("Tuesday of week 42", &config),
+                date(2024, 10, 15)
+            );
+            assert_eq!(
+                weekday_in_week("next week's Sunday", &config),
+                date(2024, 11, 24)
+            );
+        }
+
+        #[test]
+        fn test_weekday_in_week_uses_week_start() {
+            let config = ParserConfig {
+                week_start: Weekday::Sun,
+                ..Default::default()
+            };
+
+            assert_eq!(
+                weekday_in_week("next week's Sunday", &config),
+                date(2024, 11, 17)
+            );
+            // ISO weeks always start on Monday.
+            assert_eq!(
+                weekday_in_week("Sunday of week 42", &config),
+                date(2024, 10, 20)
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod process_holiday_tests {
+        use super::helping_functions::date;
+        use chrono::{DateTime, NaiveDate, TimeZone};
+        use natural_date_parser::date_parser::process_holiday;
+        use natural_date_parser::holidays::{HolidayCalendar, HolidayRule};
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, ParseDateError, ParserConfig, Rule};
+        use pest::Parser;
+        use std::sync::Arc;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn holiday(input: &str, config: &ParserConfig) -> Result<NaiveDate, ParseDateError> {
+            let pair = DateParser::parse(Rule::holiday, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_holiday(pair, get_test_datetime(), config)
+                .map(|resolved| resolved.datetime.date_naive())
+        }
+
+        #[test]
+        fn test_process_holiday_current_year() {
+            let config = ParserConfig::default();
+
+            assert_eq!(holiday("Christmas", &config).unwrap(), date(2024, 12, 25));
+            assert_eq!(holiday("Easter", &config).unwrap(), date(2024, 3, 31));
+            assert_eq!(
+                holiday("Thanksgiving 2026", &config).unwrap(),
+                date(2026, 11, 26)
+            );
+        }
+
+        #[test]
+        fn test_process_holiday_direction() {
+            let config = ParserConfig::default();
+
+            assert_eq!(holiday("next Easter", &config).unwrap(), date(2025, 4, 20));
+            assert_eq!(
+                holiday("next Thanksgiving", &config).unwrap(),
+                date(2024, 11, 28)
+            );
+            assert_eq!(
+                holiday("last Thanksgiving", &config).unwrap(),
+                date(2023, 11, 23)
+            );
+            assert_eq!(
+                holiday("last Veterans Day", &config).unwrap(),
+                date(2023, 11, 11)
+            );
+        }
+
+        #[test]
+        fn test_process_holiday_unknown() {
+            let result = holiday("Boxing Day", &ParserConfig::default());
+            assert!(result.is_err());
+            if let Err(ParseDateError::ParseError(msg)) = result {
+                assert!(msg.contains("Unknown day"));
+            }
+        }
+
+        #[test]
+        fn test_process_holiday_custom_provider() {
+            let mut calendar = HolidayCalendar::united_kingdom();
+            calendar.add(&["Company Day"], HolidayRule::Fixed { month: 6, day: 1 });
+            let config = ParserConfig {
+                holidays: Arc::new(calendar),
+                ..Default::default()
+            };
+
+            assert_eq!(holiday("Boxing Day", &config).unwrap(), date(2024, 12, 26));
+            assert_eq!(
+                holiday("next company day", &config).unwrap(),
+                date(2025, 6, 1)
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod business_day_tests {
+        use super::helping_functions::date;
+        use chrono::{DateTime, NaiveDate, TimeZone, Weekday};
+        use natural_date_parser::date_parser::{
+            business_days_between, is_business_day, process_business_day_anchor,
+            process_future_time, process_past_time, shift_business_days,
+        };
+        use natural_date_parser::holidays::{HolidayCalendar, HolidayRule};
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, ParserConfig, Rule};
+        use pest::Parser;
+        use std::sync::Arc;
+
+        fn local(year: i32, month: u32, day: u32) -> DateTime<ReferenceZone> {
+            ReferenceZone::Local
+                .with_ymd_and_hms(year, month, day, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn resolve(
+            rule: Rule,
+            input: &str,
+            datetime: DateTime<ReferenceZone>,
+            config: &ParserConfig,
+        ) -> NaiveDate {
+            let pair = DateParser::parse(rule, input).unwrap().next().unwrap();
+
+            match rule {
+                Rule::future_time => process_future_time(pair, datetime, config),
+                Rule::past_time => process_past_time(pair, datetime, config),
+                _ => process_business_day_anchor(pair, datetime, config),
+            }
+            .unwrap()
+            .datetime
+            .date_naive()
+        }
+
+        #[test]
+        fn test_business_day_offsets_skip_weekends() {
+            let config = ParserConfig::default();
+
+            assert_eq!(
+                resolve(
+                    Rule::future_time,
+                    "in 3 business days",
+                    local(2024, 11, 11),
+                    &config
+                ),
+                date(2024, 11, 14)
+            );
+            assert_eq!(
+                resolve(
+                    Rule::future_time,
+                    "in 3 working days",
+                    local(2024, 11, 15),
+                    &config
+                ),
+                date(2024, 11, 20)
+            );
+            assert_eq!(
+                resolve(
+                    Rule::past_time,
+                    "5 workdays ago",
+                    local(2024, 11, 11),
+                    &config
+                ),
+                date(2024, 11, 4)
+            );
+        }
+
+        #[test]
+        fn test_business_day_anchors() {
+            let config = ParserConfig::default();
+
+            assert_eq!(
+                resolve(
+                    Rule::business_day_anchor,
+                    "next working day",
+                    local(2024, 11, 15),
+                    &config
+                ),
+                date(2024, 11, 18)
+            );
+            assert_eq!(
+                resolve(
+                    Rule::business_day_anchor,
+                    "previous business day",
+                    local(2024, 11, 11),
+                    &config
+                ),
+                date(2024, 11, 8)
+            );
+        }
+
+        #[test]
+        fn test_business_days_with_custom_weekend() {
+            let config = ParserConfig {
+                weekend: vec![Weekday::Fri, Weekday::Sat],
+                ..Default::default()
+            };
+
+            assert_eq!(
+                resolve(
+                    Rule::business_day_anchor,
+                    "next business day",
+                    local(2024, 11, 14),
+                    &config
+                ),
+                date(2024, 11, 17)
+            );
+        }
+
+        #[test]
+        fn test_business_days_skip_holidays() {
+            let mut calendar = HolidayCalendar::new();
+            calendar.add(
+                &["Thanksgiving"],
+                HolidayRule::NthWeekday {
+                    month: 11,
+                    weekday: Weekday::Thu,
+                    n: 4,
+                },
+            );
+            let config = ParserConfig {
+                business_holidays: Arc::new(calendar),
+                ..Default::default()
+            };
+
+            assert_eq!(
+                resolve(
+                    Rule::future_time,
+                    "in 2 business days",
+                    local(2024, 11, 27),
+                    &config
+                ),
+                date(2024, 12, 2)
+            );
+        }
+
+        #[test]
+        fn test_shift_business_days_matches_counting_days() {
+            let config = ParserConfig {
+                weekend: vec![Weekday::Fri, Weekday::Sat],
+                business_holidays: Arc::new(HolidayCalendar::united_states()),
+                ..Default::default()
+            };
+
+            for start in [date(2024, 11, 27), date(2024, 12, 20), date(2025, 1, 3)] {
+                let mut expected = start;
+                for amount in 1..400 {
+                    expected = expected.succ_opt().unwrap();
+                    while !is_business_day(expected, &config) {
+                        expected = expected.succ_opt().unwrap();
+                    }
+                    assert_eq!(
+                        shift_business_days(start, amount, &config).unwrap(),
+                        expected
+                    );
+                    assert_eq!(
+                        business_days_between(start, expected, &config).unwrap(),
+                        amount
+                    );
+                    if is_business_day(start, &config) {
+                        assert_eq!(
+                            shift_business_days(expected, -amount, &config).unwrap(),
+                            start
+                        );
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn test_shift_business_days_far() {
+            let config = ParserConfig {
+                business_holidays: Arc::new(HolidayCalendar::united_states()),
+                ..Default::default()
+            };
+            let start = date(2024, 11, 11);
+
+            let end = shift_business_days(start, 3_000_000, &config).unwrap();
+            assert_eq!(
+                business_days_between(start, end, &config).unwrap(),
+                3_000_000
+            );
+            assert!(shift_business_days(start, 999_999_999, &config).is_err());
+        }
+
+        #[test]
+        fn test_business_days_between() {
+            let config = ParserConfig::default();
+
+            // Monday to the next Monday: Tuesday to Friday and Monday.
+            assert_eq!(
+                business_days_between(date(2024, 11, 11), date(2024, 11, 18), &config).unwrap(),
+                5
+            );
+            assert_eq!(
+                business_days_between(date(2024, 11, 18), date(2024, 11, 11), &config).unwrap(),
+                -5
+            );
+            assert_eq!(
+                business_days_between(date(2024, 11, 16), date(2024, 11, 17), &config).unwrap(),
+                0
+            );
+
+            let start = date(2024, 11, 27);
+            let end = shift_business_days(start, 7, &config).unwrap();
+            assert_eq!(business_days_between(start, end, &config).unwrap(), 7);
+        }
+
+        #[test]
+        fn test_shift_business_days_without_business_days() {
+            let config = ParserConfig {
+                weekend: vec![
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                    Weekday::Sat,
+                    Weekday::Sun,
+                ],
+                ..Default::default()
+            };
+
+            assert!(shift_business_days(date(2024, 11, 11), 1, &config).is_err());
+            assert!(
+                business_days_between(date(2024, 11, 11), date(2024, 11, 18), &config).is_err()
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod vocabulary_tests {
+        use super::helping_functions::date;
+        use chrono::{DateTime, Datelike, NaiveTime, TimeZone};
+        use natural_date_parser::date_parser::{
+            process_future_time, process_holiday, process_past_time, process_time_of_day,
+        };
+        use natural_date_parser::vocabulary::Vocabulary;
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, ParseDateError, ParserConfig, Rule};
+        use pest::Parser;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn config() -> ParserConfig {
+            let mut vocabulary = Vocabulary::default();
+            vocabulary.add_anchor("payday", |date| {
+                date.day() == 15 || date.succ_opt().is_some_and(|next| next.day() == 1)
+            });
+            vocabulary.add_anchor("sprint end", |date| {
+                date.weekday() == chrono::Weekday::Fri && date.iso_week().week() % 2 == 0
+            });
+            vocabulary.add_day_part("standup", NaiveTime::from_hms_opt(9, 45, 0).unwrap());
+            vocabulary.add_unit("sprint", 2, Rule::week_s).unwrap();
+
+            ParserConfig {
+                vocabulary,
+                ..Default::default()
+            }
+        }
+
+        fn resolve(
+            rule: Rule,
+            input: &str,
+            config: &ParserConfig,
+        ) -> Result<DateTime<ReferenceZone>, ParseDateError> {
+            let pair = DateParser::parse(rule, input).unwrap().next().unwrap();
+
+            match rule {
+                Rule::future_time => process_future_time(pair, get_test_datetime(), config),
+                Rule::past_time => process_past_time(pair, get_test_datetime(), config),
+                Rule::time_of_day => process_time_of_day(pair, get_test_datetime(), config),
+                _ => process_holiday(pair, get_test_datetime(), config),
+            }
+            .map(|resolved| resolved.datetime)
+        }
+
+        #[test]
+        fn test_anchor() {
+            let config = config();
+            let day = |input| resolve(Rule::holiday, input, &config).unwrap().date_naive();
+
+            assert_eq!(day("payday"), date(2024, 11, 15));
+            assert_eq!(day("next payday"), date(2024, 11, 15));
+            assert_eq!(day("last payday"), date(2024, 10, 31));
+            assert_eq!(day("payday 2025"), date(2025, 1, 15));
+            assert_eq!(day("Sprint End"), date(2024, 11, 15));
+        }
+
+        #[test]
+        fn test_anchor_today() {
+            let config = config();
+            let today = ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 15, 12, 0, 0)
+                .unwrap();
+            let pair = |input| {
+                DateParser::parse(Rule::holiday, input)
+                    .unwrap()
+                    .next()
+                    .unwrap()
+            };
+
+            assert_eq!(
+                process_holiday(pair("payday"), today, &config)
+                    .unwrap()
+                    .datetime
+                    .date_naive(),
+                date(2024, 11, 15)
+            );
+            assert_eq!(
+                process_holiday(pair("next payday"), today, &config)
+                    .unwrap()
+                    .datetime
+                    .date_naive(),
+                date(2024, 11, 30)
+            );
+        }
+
+        #[test]
+        fn test_anchor_without_occurrence() {
+            let mut config = config();
+            config.vocabulary.add_anchor("never", |_| false);
+
+            assert!(resolve(Rule::holiday, "never", &config).is_err());
+        }
+
+        #[test]
+        fn test_day_part() {
+            let config = config();
+
+            assert_eq!(
+                resolve(Rule::time_of_day, "standup", &config)
+                    .unwrap()
+                    .naive_local(),
+                date(2024, 11, 11).and_hms_opt(9, 45, 0).unwrap()
+            );
+            assert_eq!(
+                resolve(Rule::time_of_day, "noon", &config)
+                    .unwrap()
+                    .naive_local(),
+                date(2024, 11, 11).and_hms_opt(12, 0, 0).unwrap()
+            );
+            assert_eq!(
+                resolve(Rule::holiday, "midnight", &config)
+                    .unwrap()
+                    .naive_local(),
+                date(2024, 11, 11).and_hms_opt(0, 0, 0).unwrap()
+            );
+            assert!(resolve(Rule::holiday, "next standup", &config).is_err());
+            assert!(resolve(Rule::time_of_day, "brunch", &config).is_err());
+        }
+
+        #[test]
+        fn test_unit() {
+            let config = config();
+            let day = |rule, input| resolve(rule, input, &config).unwrap().date_naive();
+
+            assert_eq!(day(Rule::future_time, "in 1 sprint"), date(2024, 11, 25));
+            assert_eq!(day(Rule::future_time, "in two sprints"), date(2024, 12, 9));
+            assert_eq!(day(Rule::past_time, "a fortnight ago"), date(2024, 10, 28));
+            assert!(resolve(Rule::future_time, "in 3 eons", &config).is_err());
+            assert!(resolve(Rule::future_time, "in half a sprint", &config).is_err());
+        }
+
+        #[test]
+        fn test_add_unit_rejects_non_unit_rules() {
+            let mut vocabulary = Vocabulary::new();
+
+            assert!(vocabulary.add_unit("sprint", 2, Rule::week_s).is_ok());
+            assert!(vocabulary.add_unit("cycle", 2, Rule::specific_day).is_err());
+            assert_eq!(vocabulary.unit("Sprints"), Some((2, Rule::week_s)));
+            assert_eq!(vocabulary.unit("fortnight"), None);
+        }
+    }
+
+    #[cfg(test)]
+    mod time_zone_tests {
+        use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+        use chrono_tz::Tz;
+        use natural_date_parser::date_parser::{parse_with_config, process_time_of_day};
+        use natural_date_parser::zones::{ReferenceZone, Zone};
+        use natural_date_parser::{DateParser, ParseDateError, ParserConfig, Rule};
+        use pest::Parser;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn resolve(rule: Rule, input: &str) -> Result<DateTime<Utc>, ParseDateError> {
+            let pair = DateParser::parse(rule, input).unwrap().next().unwrap();
+
+            process_time_of_day(pair, get_test_datetime(), &ParserConfig::default())
+                .map(|resolved| resolved.datetime.with_timezone(&Utc))
+        }
+
+        fn utc(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+            Utc.with_ymd_and_hms(2024, 11, day, hour, minute, 0)
+                .unwrap()
+        }
+
+        #[test]
+        fn test_zone_abbreviations() {
+            assert_eq!(
+                resolve(Rule::time_expression, "3pm EST").unwrap(),
+                utc(11, 20, 0)
+            );
+            assert_eq!(
+                resolve(Rule::time_expression, "3 PM PT").unwrap(),
+                utc(11, 23, 0)
+            );
+            assert_eq!(
+                resolve(Rule::time_expression, "9:30 AM UTC").unwrap(),
+                utc(11, 9, 30)
+            );
+            assert!(resolve(Rule::time_expression, "3pm XYZ").is_err());
+        }
+
+        #[test]
+        fn test_utc_offsets() {
+            assert_eq!(
+                resolve(Rule::time_expression, "9:00 UTC+2").unwrap(),
+                utc(11, 7, 0)
+            );
+            assert_eq!(
+                resolve(Rule::time_expression, "17:30 GMT-05:30").unwrap(),
+                utc(11, 23, 0)
+            );
+        }
+
+        #[test]
+        fn test_iana_names_and_cities() {
+            assert_eq!(
+                resolve(Rule::time_expression, "10 AM Europe/Kyiv").unwrap(),
+                utc(11, 8, 0)
+            );
+            assert_eq!(
+                resolve(Rule::time_expression, "noon in Tokyo").unwrap(),
+                utc(11, 3, 0)
+            );
+            assert_eq!(
+                resolve(Rule::time_of_day, "9:00 in New York").unwrap(),
+                utc(11, 14, 0)
+            );
+            assert!(resolve(Rule::time_expression, "10 AM Europe/Atlantis").is_err());
+            assert!(resolve(Rule::time_expression, "noon in Atlantis").is_err());
+        }
+
+        #[test]
+        fn test_24_hour_clock() {
+            let pair = DateParser::parse(Rule::time_of_day, "17:45")
+                .unwrap()
+                .next()
+                .unwrap();
+            let datetime = process_time_of_day(pair, get_test_datetime(), &ParserConfig::default())
+                .unwrap()
+                .datetime;
+
+            assert_eq!(
+                datetime.naive_local(),
+                get_test_datetime()
+                    .date_naive()
+                    .and_hms_opt(17, 45, 0)
+                    .unwrap()
+            );
+            assert!(resolve(Rule::time_of_day, "24:00").is_err());
+        }
+
+        #[test]
+        fn test_parse_records_source_zone() {
+            let config = ParserConfig {
+                target_zone: Some(Zone::Named(Tz::Asia__Tokyo)),
+                ..Default::default()
+            };
+            let parsed = parse_with_config("tomorrow at 3pm CST", &config).unwrap();
+            let source_zone = parsed.source_zone.unwrap();
+
+            assert_eq!(source_zone.text, "CST");
+            assert_eq!(
+                source_zone.zone,
+                Zone::Fixed(FixedOffset::west_opt(6 * 3600).unwrap())
+            );
+            assert_eq!(
+                source_zone.alternatives,
+                vec![Zone::Fixed(FixedOffset::east_opt(8 * 3600).unwrap())]
+            );
+            assert_eq!(
+                parsed.datetime.offset(),
+                &FixedOffset::east_opt(9 * 3600).unwrap()
+            );
+        }
+
+        #[test]
+        fn test_parse_without_zone() {
+            let parsed = parse_with_config("tomorrow at 3pm", &ParserConfig::default()).unwrap();
+
+            assert_eq!(parsed.source_zone, None);
+            assert_eq!(
+                parsed.datetime.naive_local().time(),
+                chrono::NaiveTime::from_hms_opt(15, 0, 0).unwrap()
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod dst_policy_tests {
+        use chrono::{TimeZone, Utc};
+        use natural_date_parser::ParserConfig;
+        use natural_date_parser::date_parser::parse_with_config;
+        use natural_date_parser::zones::{AmbiguousTime, DstPolicy, DstResolution, SkippedTime};
+
+        #[test]
+        fn test_parse_reports_ambiguous_time() {
+            let input = "the first Sunday of November 2024 at 1:30 AM America/New_York";
+
+            let parsed = parse_with_config(input, &ParserConfig::default()).unwrap();
+            assert_eq!(
+                parsed.datetime,
+                Utc.with_ymd_and_hms(2024, 11, 3, 5, 30, 0).unwrap()
+            );
+            assert_eq!(parsed.dst_resolutions, vec![DstResolution::Earliest]);
+
+            let config = ParserConfig {
+                dst_policy: DstPolicy {
+                    ambiguous: AmbiguousTime::Latest,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            let parsed = parse_with_config(input, &config).unwrap();
+            assert_eq!(
+                parsed.datetime,
+                Utc.with_ymd_and_hms(2024, 11, 3, 6, 30, 0).unwrap()
+            );
+            assert_eq!(parsed.dst_resolutions, vec![DstResolution::Latest]);
+        }
+
+        #[test]
+        fn test_parse_reports_skipped_time() {
+            let input = "the second Sunday of March 2024 at 2:30 AM America/New_York";
+
+            let parsed = parse_with_config(input, &ParserConfig::default()).unwrap();
+            assert_eq!(
+                parsed.datetime,
+                Utc.with_ymd_and_hms(2024, 3, 10, 7, 30, 0).unwrap()
+            );
+            assert_eq!(parsed.dst_resolutions, vec![DstResolution::ShiftedForward]);
+
+            let config = ParserConfig {
+                dst_policy: DstPolicy {
+                    skipped: SkippedTime::Error,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            assert!(parse_with_config(input, &config).is_err());
+        }
+
+        #[test]
+        fn test_parse_without_dst_change() {
+            let input = "the second Sunday of March 2024 at 4:00 AM America/New_York";
+            let parsed = parse_with_config(input, &ParserConfig::default()).unwrap();
+
+            assert_eq!(
+                parsed.datetime,
+                Utc.with_ymd_and_hms(2024, 3, 10, 8, 0, 0).unwrap()
+            );
+            assert_eq!(parsed.dst_resolutions, Vec::new());
+        }
+    }
+
+    #[cfg(test)]
+    mod anchored_date_tests {
+        use super::helping_functions::date;
+        use chrono::{DateTime, NaiveDate, TimeZone, Timelike};
+        use natural_date_parser::date_parser::process_anchored_date;
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, ParseDateError, ParserConfig, Rule};
+        use pest::Parser;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn anchored(input: &str) -> Result<DateTime<ReferenceZone>, ParseDateError> {
+            let pair = DateParser::parse(Rule::anchored_date, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_anchored_date(pair, get_test_datetime(), &ParserConfig::default())
+                .map(|resolved| resolved.datetime)
+        }
+
+        fn anchored_day(input: &str) -> NaiveDate {
+            anchored(input).unwrap().date_naive()
+        }
+
+        #[test]
+        fn test_offset_from_anchor() {
+            assert_eq!(
+                anchored_day("2 days after the second Tuesday of December"),
+                date(2024, 12, 12)
+            );
+            assert_eq!(
+                anchored_day("a week and 2 days before New Year's Day 2025"),
+                date(2024, 12, 23)
+            );
+            assert_eq!(
+                anchored_day("3 business days after Christmas"),
+                date(2024, 12, 30)
+            );
+            assert_eq!(
+                anchored_day("the day after Thanksgiving"),
+                date(2024, 11, 29)
+            );
+            assert_eq!(anchored_day("the day before tomorrow"), date(2024, 11, 11));
+        }
+
+        #[test]
+        fn test_weekday_from_anchor() {
+            // Christmas 2024 is a Wednesday.
+            assert_eq!(
+                anchored_day("the Friday before Christmas"),
+                date(2024, 12, 20)
+            );
+            assert_eq!(
+                anchored_day("the Monday after Christmas"),
+                date(2024, 12, 30)
+            );
+            assert_eq!(
+                anchored_day("the Wednesday before Christmas"),
+                date(2024, 12, 18)
+            );
+            assert_eq!(anchored_day("Wednesday after Christmas"), date(2025, 1, 1));
+        }
+
+        #[test]
+        fn test_nested_anchors() {
+            assert_eq!(
+                anchored_day("the Friday after the Friday after Christmas"),
+                date(2025, 1, 3)
+            );
+            assert_eq!(
+                anchored_day("the Monday after 2 weeks after Easter 2025"),
+                date(2025, 5, 5)
+            );
+        }
+
+        #[test]
+        fn test_anchor_keeps_time_of_day() {
+            let datetime = anchored("2 hours before the end of Christmas").unwrap();
+
+            assert_eq!(datetime.date_naive(), date(2024, 12, 25));
+            assert_eq!((datetime.hour(), datetime.minute()), (21, 59));
+        }
+
+        #[test]
+        fn test_invalid_anchor() {
+            assert!(anchored("2 days after Festivus").is_err());
+        }
+    }
+
+    #[cfg(test)]
+    mod ambiguity_tests {
+        use super::helping_functions::date;
+        use chrono::{DateTime, Datelike, NaiveDate, TimeZone, Timelike, Weekday};
+        use natural_date_parser::date_parser::{
+            parse_all, parse_all_with_config, process_bare_hour, process_numeric_date,
+        };
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{
+            DateOrder, DateParser, HourReading, ParserConfig, Readings, Rule, WeekdayReading,
+        };
+        use pest::Parser;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn with_readings(readings: Readings) -> ParserConfig {
+            ParserConfig {
+                readings,
+                ..Default::default()
+            }
+        }
+
+        fn numeric_date(input: &str, date_order: DateOrder) -> Option<NaiveDate> {
+            let pair = DateParser::parse(Rule::numeric_date, input)
+                .unwrap()
+                .next()
+                .unwrap();
+            let config = with_readings(Readings {
+                date_order,
+                ..Default::default()
+            });
+
+            process_numeric_date(pair, get_test_datetime(), &config)
+                .ok()
+                .map(|resolved| resolved.datetime.date_naive())
+        }
+
+        fn bare_hour(input: &str, hour: HourReading) -> u32 {
+            let pair = DateParser::parse(Rule::bare_hour, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_bare_hour(
+                pair,
+                &with_readings(Readings {
+                    hour,
+                    ..Default::default()
+                }),
+            )
+            .unwrap()
+        }
+
+        #[test]
+        fn test_numeric_date_order() {
+            assert_eq!(
+                numeric_date("03/04/2025", DateOrder::MonthFirst),
+                Some(date(2025, 3, 4))
+            );
+            assert_eq!(
+                numeric_date("03/04/2025", DateOrder::DayFirst),
+                Some(date(2025, 4, 3))
+            );
+            assert_eq!(
+                numeric_date("3.4.25", DateOrder::DayFirst),
+                Some(date(2025, 4, 3))
+            );
+            assert_eq!(
+                numeric_date("12/25", DateOrder::MonthFirst),
+                Some(date(2024, 12, 25))
+            );
+            assert_eq!(numeric_date("13/04/2025", DateOrder::MonthFirst), None);
+            assert_eq!(numeric_date("02/30/2025", DateOrder::MonthFirst), None);
+        }
+
+        #[test]
+        fn test_bare_hour_readings() {
+            assert_eq!(bare_hour("3", HourReading::Likely), 15);
+            assert_eq!(bare_hour("7", HourReading::Likely), 7);
+            assert_eq!(bare_hour("12", HourReading::Likely), 12);
+            assert_eq!(bare_hour("7", HourReading::Pm), 19);
+            assert_eq!(bare_hour("12", HourReading::Am), 0);
+            assert_eq!(bare_hour("3", HourReading::Am), 3);
+            assert_eq!(bare_hour("19", HourReading::Am), 19);
+            assert_eq!(bare_hour("0", HourReading::Pm), 0);
+        }
+
+        #[test]
+        fn test_parse_all_numeric_date() {
+            let interpretations = parse_all("03/04/2025").unwrap();
+
+            assert_eq!(interpretations.len(), 2);
+            assert_eq!(
+                interpretations[0].parsed.datetime.date_naive(),
+                date(2025, 3, 4)
+            );
+            assert_eq!(
+                interpretations[0].readings.date_order,
+                DateOrder::MonthFirst
+            );
+            assert!((interpretations[0].score - 0.7).abs() < 1e-9);
+            assert_eq!(
+                interpretations[1].parsed.datetime.date_naive(),
+                date(2025, 4, 3)
+            );
+            assert!((interpretations[1].score - 0.3).abs() < 1e-9);
+        }
+
+        #[test]
+        fn test_parse_all_preferred_date_order() {
+            let config = with_readings(Readings {
+                date_order: DateOrder::DayFirst,
+                ..Default::default()
+            });
+            let interpretations = parse_all_with_config("03/04/2025", &config).unwrap();
+
+            assert_eq!(
+                interpretations[0].parsed.datetime.date_naive(),
+                date(2025, 4, 3)
+            );
+        }
+
+        #[test]
+        fn test_parse_all_single_reading() {
+            let interpretations = parse_all("13/04/2025").unwrap();
+
+            assert_eq!(interpretations.len(), 1);
+            assert_eq!(
+                interpretations[0].parsed.datetime.date_naive(),
+                date(2025, 4, 13)
+            );
+            assert!((interpretations[0].score - 1.0).abs() < 1e-9);
+
+            // Both orders give the same date, so the readings are merged.
+            assert_eq!(parse_all("05/05/2025").unwrap().len(), 1);
+            assert_eq!(parse_all("tomorrow at 5 PM").unwrap().len(), 1);
+        }
+
+        #[test]
+        fn test_parse_all_bare_hour() {
+            let interpretations = parse_all("03/04/2025 at 7").unwrap();
+            let hours: Vec<_> = interpretations
+                .iter()
+                .map(|i| (i.parsed.datetime.day(), i.parsed.datetime.hour()))
+                .collect();
+
+            assert_eq!(hours, [(4, 7), (4, 19), (3, 7), (3, 19)]);
+            let total: f64 = interpretations.iter().map(|i| i.score).sum();
+            assert!((total - 1.0).abs() < 1e-9);
+
+            let interpretations = parse_all("03/04/2025 at 3").unwrap();
+            assert_eq!(interpretations[0].parsed.datetime.hour(), 15);
+            assert_eq!(interpretations[0].readings.hour, HourReading::Pm);
+        }
+
+        #[test]
+        fn test_parse_all_weekday() {
+            let interpretations = parse_all("Friday").unwrap();
+            let today = ReferenceZone::Local.now().date_naive();
+
+            assert_eq!(interpretations.len(), 2);
+            assert_eq!(
+                interpretations[0].readings.weekday,
+                WeekdayReading::Upcoming
+            );
+            assert!(interpretations[0].parsed.datetime.date_naive() >= today);
+            assert_eq!(
+                interpretations[1].readings.weekday,
+                WeekdayReading::Previous
+            );
+            assert!(interpretations[1].parsed.datetime.date_naive() < today);
+            for interpretation in &interpretations {
+                assert_eq!(interpretation.parsed.datetime.weekday(), Weekday::Fri);
+            }
+        }
+
+        #[test]
+        fn test_parse_all_invalid() {
+            assert!(parse_all("02/30/2025").is_err());
+            assert!(parse_all("not a date").is_err());
+        }
+    }
+
+    #[cfg(test)]
+    mod bound_tests {
+        use super::helping_functions::at;
+        use chrono::{DateTime, NaiveDate, NaiveTime, TimeZone};
+        use natural_date_parser::date_parser::{process_bound_expression, process_calendar_date};
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{BoundKind, DateBound, DateParser, ParserConfig, Rule};
+        use pest::Parser;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn bound(input: &str) -> DateBound {
+            let pair = DateParser::parse(Rule::bound_expression, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_bound_expression(pair, get_test_datetime(), &ParserConfig::default()).unwrap()
+        }
+
+        fn calendar_date(input: &str) -> Option<NaiveDate> {
+            let pair = DateParser::parse(Rule::calendar_date, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            process_calendar_date(pair, get_test_datetime(), &ParserConfig::default())
+                .ok()
+                .map(|resolved| resolved.datetime.date_naive())
+        }
+
+        #[test]
+        fn test_calendar_date() {
+            assert_eq!(
+                calendar_date("March 3"),
+                NaiveDate::from_ymd_opt(2024, 3, 3)
+            );
+            assert_eq!(
+                calendar_date("March 3rd, 2026"),
+                NaiveDate::from_ymd_opt(2026, 3, 3)
+            );
+            assert_eq!(
+                calendar_date("the 21st of December"),
+                NaiveDate::from_ymd_opt(2024, 12, 21)
+            );
+            assert_eq!(calendar_date("February 30"), None);
+        }
+
+        #[test]
+        fn test_deadlines() {
+            let by = bound("by Friday");
+            assert_eq!(by.kind, BoundKind::Upper);
+            assert!(by.inclusive);
+            assert_eq!(by.datetime, at(2024, 11, 15, 23, 59, 59));
+
+            let before = bound("before Friday");
+            assert_eq!(before.kind, BoundKind::Upper);
+            assert!(!before.inclusive);
+            assert_eq!(before.datetime, at(2024, 11, 15, 0, 0, 0));
+
+            assert_eq!(
+                bound("no later than March 3").datetime,
+                at(2024, 3, 3, 23, 59, 59)
+            );
+            assert_eq!(
+                bound("until Christmas").datetime,
+                at(2024, 12, 25, 23, 59, 59)
+            );
+        }
+
+        #[test]
+        fn test_deadlines_on_a_weekend() {
+            // Saturday, November 16, 2024
+            let saturday = ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 16, 12, 0, 0)
+                .unwrap();
+            let bound = |input: &str| {
+                let pair = DateParser::parse(Rule::bound_expression, input)
+                    .unwrap()
+                    .next()
+                    .unwrap();
+                process_bound_expression(pair, saturday, &ParserConfig::default()).unwrap()
+            };
+
+            assert_eq!(bound("by Friday").datetime, at(2024, 11, 22, 23, 59, 59));
+            assert_eq!(bound("before Monday").datetime, at(2024, 11, 18, 0, 0, 0));
+            assert_eq!(bound("by Saturday").datetime, at(2024, 11, 16, 23, 59, 59));
+        }
+
+        #[test]
+        fn test_starting_points() {
+            let since = bound("since Friday");
+            assert_eq!(since.kind, BoundKind::Lower);
+            assert!(since.inclusive);
+            assert_eq!(since.datetime, at(2024, 11, 15, 0, 0, 0));
+
+            let after = bound("after Friday");
+            assert_eq!(after.kind, BoundKind::Lower);
+            assert!(!after.inclusive);
+            assert_eq!(after.datetime, at(2024, 11, 15, 23, 59, 59));
+
+            assert!(bound("no earlier than tomorrow").inclusive);
+        }
+
+        #[test]
+        fn test_explicit_times_are_kept() {
+            assert_eq!(
+                bound("before tomorrow noon").datetime,
+                at(2024, 11, 12, 12, 0, 0)
+            );
+            assert_eq!(bound("by 5 PM").datetime, at(2024, 11, 11, 17, 0, 0));
+            assert_eq!(
+                bound("after March 3 at 9:30 AM").datetime,
+                at(2024, 3, 3, 9, 30, 0)
+            );
+            assert_eq!(bound("by EOD").datetime, at(2024, 11, 11, 23, 59, 59));
+        }
+
+        #[test]
+        fn test_end_of_day_setting() {
+            let pair = DateParser::parse(Rule::bound_expression, "by Friday")
+                .unwrap()
+                .next()
+                .unwrap();
+            let config = ParserConfig {
+                end_of_day: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+                ..Default::default()
+            };
+            let bound = process_bound_expression(pair, get_test_datetime(), &config).unwrap();
+
+            assert_eq!(bound.datetime, at(2024, 11, 15, 17, 0, 0));
+        }
+
+        #[test]
+        fn test_contains() {
+            let by = bound("by Friday");
+            assert!(by.contains(&at(2024, 11, 15, 18, 0, 0)));
+            assert!(by.contains(&at(2024, 11, 15, 23, 59, 59)));
+            assert!(!by.contains(&at(2024, 11, 16, 0, 0, 0)));
+
+            let after = bound("after Friday");
+            assert!(!after.contains(&at(2024, 11, 15, 18, 0, 0)));
+            assert!(after.contains(&at(2024, 11, 16, 0, 0, 0)));
+        }
+    }
+
+    #[cfg(test)]
+    mod fuzzy_date_tests {
+        use super::helping_functions::at;
+        use chrono::{DateTime, Duration, TimeZone};
+        use natural_date_parser::date_parser::{parse, process_fuzzy_date};
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{DateParser, DateRange, ParserConfig, Rule, Tolerances};
+        use pest::Parser;
+
+        fn get_test_datetime() -> DateTime<ReferenceZone> {
+            // Monday, November 11, 2024
+            ReferenceZone::Local
+                .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                .unwrap()
+        }
+
+        fn fuzzy_with(input: &str, config: &ParserConfig) -> (DateRange, DateTime<ReferenceZone>) {
+            let pair = DateParser::parse(Rule::fuzzy_date, input)
+                .unwrap()
+                .next()
+                .unwrap();
+
+            let (range, center) = process_fuzzy_date(pair, get_test_datetime(), config).unwrap();
+            (range, center.datetime)
+        }
+
+        fn fuzzy(input: &str) -> (DateRange, DateTime<ReferenceZone>) {
+            fuzzy_with(input, &ParserConfig::default())
+        }
+
+        #[test]
+        fn test_approximate_time() {
+            let (range, center) = fuzzy("around 3pm");
+
+            assert_eq!(center, at(2024, 11, 11, 15, 0, 0));
+            assert_eq!(range.start, at(2024, 11, 11, 14, 30, 0));
+            assert_eq!(range.end, at(2024, 11, 11, 15, 30, 0));
+        }
+
+        #[test]
+        fn test_approximate_day() {
+            let (range, center) = fuzzy("about Friday");
+
+            assert_eq!(center, at(2024, 11, 15, 12, 0, 0));
+            assert_eq!(range.start, at(2024, 11, 14, 0, 0, 0));
+            assert_eq!(range.end, at(2024, 11, 17, 0, 0, 0));
+        }
+
+        #[test]
+        fn test_custom_tolerances() {
+            let config = ParserConfig {
+                tolerances: Tolerances {
+                    time: Duration::hours(1),
+                    days: 2,
+                },
+                ..Default::default()
+            };
+
+            let (range, _) = fuzzy_with("roughly 3pm", &config);
+            assert_eq!(range.start, at(2024, 11, 11, 14, 0, 0));
+            assert_eq!(range.end, at(2024, 11, 11, 16, 0, 0));
+
+            let (range, _) = fuzzy_with("roughly Friday", &config);
+            assert_eq!(range.start, at(2024, 11, 13, 0, 0, 0));
+            assert_eq!(range.end, at(2024, 11, 18, 0, 0, 0));
+        }
+
+        #[test]
+        fn test_period_thirds() {
+            let (range, _) = fuzzy("early next week");
+            assert_eq!(range.start, at(2024, 11, 18, 0, 0, 0));
+            assert_eq!(range.end, at(2024, 11, 20, 0, 0, 0));
+
+            let (range, _) = fuzzy("late next week");
+            assert_eq!(range.start, at(2024, 11, 23, 0, 0, 0));
+            assert_eq!(range.end, at(2024, 11, 25, 0, 0, 0));
+
+            let (range, center) = fuzzy("mid March 2025");
+            assert_eq!(range.start, at(2025, 3, 11, 0, 0, 0));
+            assert_eq!(range.end, at(2025, 3, 22, 0, 0, 0));
+            assert_eq!(center, at(2025, 3, 16, 12, 0, 0));
+
+            let (range, _) = fuzzy("mid-2026");
+            assert_eq!(range.start, at(2026, 5, 3, 0, 0, 0));
+            assert_eq!(range.end, at(2026, 9, 1, 0, 0, 0));
+        }
+
+        #[test]
+        fn test_day_thirds() {
+            let (range, center) = fuzzy("late tomorrow");
+
+            assert_eq!(range.start, at(2024, 11, 12, 16, 0, 0));
+            assert_eq!(range.end, at(2024, 11, 13, 0, 0, 0));
+            assert_eq!(center, at(2024, 11, 12, 20, 0, 0));
+
+            let (range, _) = fuzzy("early tomorrow");
+            assert_eq!(range.start, at(2024, 11, 12, 0, 0, 0));
+            assert_eq!(range.end, at(2024, 11, 12, 8, 0, 0));
+        }
+
+        #[test]
+        fn test_parse_reports_uncertainty() {
+            let parsed = parse("late March 2025").unwrap();
+            let uncertainty = parsed.uncertainty.unwrap();
+
+            assert_eq!(uncertainty.start, at(2025, 3, 22, 0, 0, 0));
+            assert_eq!(uncertainty.end, at(2025, 4, 1, 0, 0, 0));
+            assert!(uncertainty.start <= parsed.datetime && parsed.datetime < uncertainty.end);
+
+            assert_eq!(parse("March 3 2025").unwrap().uncertainty, None);
+        }
+    }
+
+    #[cfg(test)]
+    mod reference_time_tests {
+        use super::helping_functions::{at, pinned};
+        use chrono::{FixedOffset, TimeZone, Utc};
+        use chrono_tz::America::New_York;
+        use natural_date_parser::ParserConfig;
+        use natural_date_parser::date_parser::{
+            bound_from_string_with_config, from_string_with_config, parse_all_with_config,
+            parse_with_config, range_from_string_with_config,
+        };
+        use natural_date_parser::zones::ReferenceZone;
+
+        #[test]
+        fn test_default_is_current_time() {
+            let before = ReferenceZone::Local.now();
+            let now = ParserConfig::default().reference_time();
+
+            assert!(before <= now && now <= ReferenceZone::Local.now());
+        }
+
+        #[test]
+        fn test_reference_time_converts_to_local() {
+            let config = ParserConfig {
+                now: Some(
+                    FixedOffset::east_opt(5 * 3600)
+                        .unwrap()
+                        .with_ymd_and_hms(2024, 11, 11, 17, 0, 0)
+                        .unwrap(),
+                ),
+                ..Default::default()
+            };
+
+            assert_eq!(
+                config.reference_time(),
+                Utc.with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+                    .unwrap()
+                    .with_timezone(&ReferenceZone::Local)
+            );
+        }
+
+        #[test]
+        fn test_pinned_parsing() {
+            let config = pinned();
+
+            assert_eq!(
+                from_string_with_config("tomorrow at 5 PM", &config).unwrap(),
+                at(2024, 11, 12, 17, 0, 0)
+            );
+            assert_eq!(
+                parse_with_config("in 3 hours", &config).unwrap().datetime,
+                at(2024, 11, 11, 15, 0, 0)
+            );
+            assert_eq!(
+                parse_all_with_config("tomorrow at 5 PM", &config).unwrap()[0]
+                    .parsed
+                    .datetime,
+                at(2024, 11, 12, 17, 0, 0)
+            );
+            assert_eq!(
+                range_from_string_with_config("next month", &config)
+                    .unwrap()
+                    .start,
+                at(2024, 12, 1, 0, 0, 0)
+            );
+            assert_eq!(
+                bound_from_string_with_config("before tomorrow", &config)
+                    .unwrap()
+                    .datetime,
+                at(2024, 11, 12, 0, 0, 0)
+            );
+        }
+
+        #[test]
+        fn test_expressions_resolve_in_zone() {
+            // 3 AM UTC on November 11 is still November 10 in New York.
+            let config = ParserConfig {
+                now: Some(
+                    Utc.with_ymd_and_hms(2024, 11, 11, 3, 0, 0)
+                        .unwrap()
+                        .fixed_offset(),
+                ),
+                zone: Some(New_York),
+                ..Default::default()
+            };
+            let new_york = |day: u32, hour: u32| {
+                New_York
+                    .with_ymd_and_hms(2024, 11, day, hour, 0, 0)
+                    .unwrap()
+            };
+
+            assert_eq!(config.reference_time(), new_york(10, 22));
+            assert_eq!(
+                from_string_with_config("tomorrow at 9am", &config).unwrap(),
+                new_york(11, 9)
+            );
+            assert_eq!(
+                range_from_string_with_config("tomorrow", &config)
+                    .unwrap()
+                    .start,
+                new_york(11, 0)
+            );
+            assert_eq!(
+                parse_with_config("tomorrow at 9am", &config)
+                    .unwrap()
+                    .datetime
+                    .offset()
+                    .local_minus_utc(),
+                -5 * 3600
+            );
+        }
+    }
+
+    mod recurrence_tests {
+        use super::helping_functions::{at, pinned};
+        use chrono::DateTime;
+        use natural_date_parser::date_parser::{
+            duration_from_string, recurrence_from_string_with_config,
+        };
+        use natural_date_parser::zones::ReferenceZone;
+        use natural_date_parser::{ParserConfig, Rule};
+
+        fn first_occurrences(input: &str, count: usize) -> Vec<DateTime<ReferenceZone>> {
+            let config = pinned();
+            recurrence_from_string_with_config(input, &config)
+                .unwrap()
+                .occurrences(&config)
+                .take(count)
+                .collect::<Result<_, _>>()
+                .unwrap()
+        }
+
+        #[test]
+        fn test_duration_from_string() {
+            assert_eq!(
+                duration_from_string("2 hours and 30 minutes").unwrap(),
+                vec![(2, Rule::hour_s), (30, Rule::minute_s)]
+            );
+            assert!(duration_from_string("in 2 hours").is_err());
+        }
+
+        #[test]
+        fn test_every_weekday_of_month() {
+            assert_eq!(
+                first_occurrences("every 2nd Tuesday", 3),
+                vec![
+                    at(2024, 11, 12, 12, 0, 0),
+                    at(2024, 12, 10, 12, 0, 0),
+                    at(2025, 1, 14, 12, 0, 0)
+                ]
+            );
+            assert_eq!(
+                first_occurrences("every last Friday of the month at 9am", 2),
+                vec![at(2024, 11, 29, 9, 0, 0), at(2024, 12, 27, 9, 0, 0)]
+            );
+            // The second Monday of November is the reference day, at noon.
+            assert_eq!(
+                first_occurrences("every second Monday at 9am", 1),
+                vec![at(2024, 12, 9, 9, 0, 0)]
+            );
+            assert!(recurrence_from_string_with_config("every 5th Friday", &pinned()).is_err());
+        }
+
+        #[test]
+        fn test_include_reference_time() {
+            let config = pinned();
+            let mut recurrence = recurrence_from_string_with_config("every day", &config).unwrap();
+            recurrence
+                .include_reference_time(&config.reference_time(), &config)
+                .unwrap();
+            assert_eq!(
+                recurrence.nth(0, &config).unwrap(),
+                at(2024, 11, 11, 12, 0, 0)
+            );
+
+            let mut recurrence =
+                recurrence_from_string_with_config("every day at 9am", &config).unwrap();
+            recurrence
+                .include_reference_time(&config.reference_time(), &config)
+                .unwrap();
+            assert_eq!(
+                recurrence.nth(0, &config).unwrap(),
+                at(2024, 11, 12, 9, 0, 0)
+            );
+        }
+
+        #[test]
+        fn test_every_unit() {
+            assert_eq!(
+                first_occurrences("every day", 2),
+                vec![at(2024, 11, 12, 12, 0, 0), at(2024, 11, 13, 12, 0, 0)]
+            );
+            assert_eq!(
+                first_occurrences("every 2 weeks", 2),
+                vec![at(2024, 11, 25, 12, 0, 0), at(2024, 12, 9, 12, 0, 0)]
+            );
+            assert_eq!(
+                first_occurrences("every day at 9am", 2),
+                vec![at(2024, 11, 12, 9, 0, 0), at(2024, 11, 13, 9, 0, 0)]
+            );
+            assert_eq!(
+                first_occurrences("every day at 3pm", 2),
+                vec![at(2024, 11, 11, 15, 0, 0), at(2024, 11, 12, 15, 0, 0)]
+            );
+        }
+
+        #[test]
+        fn test_every_weekday() {
+            assert_eq!(
+                first_occurrences("every Wednesday", 2),
+                vec![at(2024, 11, 13, 12, 0, 0), at(2024, 11, 20, 12, 0, 0)]
+            );
+            assert_eq!(
+                first_occurrences("every other Monday at 9am", 2),
+                vec![at(2024, 11, 25, 9, 0, 0), at(2024, 12, 9, 9, 0, 0)]
+            );
+            assert_eq!(
+                first_occurrences("every Monday at 5 PM", 2),
+                vec![at(2024, 11, 11, 17, 0, 0), at(2024, 11, 18, 17, 0, 0)]
+            );
+        }
+
+        #[test]
+        fn test_every_business_day() {
+            // Friday, November 15, 2024
+            let config = ParserConfig {
+                now: Some(at(2024, 11, 15, 12, 0, 0).fixed_offset()),
+                ..Default::default()
+            };
+            let recurrence =
+                recurrence_from_string_with_config("every business day", &config).unwrap();
+
+            assert_eq!(
+                recurrence.nth(0, &config).unwrap(),
+                at(2024, 11, 18, 12, 0, 0)
+            );
+            assert_eq!(
+                recurrence.nth(1, &config).unwrap(),
+                at(2024, 11, 19, 12, 0, 0)
+            );
+        }
+
+        #[test]
+        fn test_months_are_counted_from_the_first_occurrence() {
+            let config = ParserConfig {
+                now: Some(at(2025, 1, 30, 12, 0, 0).fixed_offset()),
+                ..Default::default()
+            };
+            let recurrence = recurrence_from_string_with_config("every month", &config).unwrap();
+            let occurrences: Vec<_> = recurrence
+                .occurrences(&config)
+                .take(3)
+                .collect::<Result<_, _>>()
+                .unwrap();
+
+            assert_eq!(
+                occurrences,
+                vec![
+                    at(2025, 2, 28, 12, 0, 0),
+                    at(2025, 3, 30, 12, 0, 0),
+                    at(2025, 4, 30, 12, 0, 0)
+                ]
+            );
+        }
+
+        #[test]
+        fn test_invalid_recurrence() {
+            let config = pinned();
+
+            assert!(recurrence_from_string_with_config("every 0 days", &config).is_err());
+            assert!(recurrence_from_string_with_config("next Monday", &config).is_err());
+        }
+    }
+
+    mod trace_tests {
+        use super::helping_functions::{at, pinned_at};
+        use natural_date_parser::ParserConfig;
+        use natural_date_parser::date_parser::{from_string_with_config, parse_with_config, trace};
+
+        fn pinned() -> ParserConfig {
+            // Sunday, November 10, 2024
+            pinned_at(at(2024, 11, 10, 12, 0, 0))
+        }
+
+        #[test]
+        fn test_steps_in_order() {
+            let config = pinned();
+            let (result, steps) =
+                trace(|| from_string_with_config("2 days after next Monday", &config));
+            let names: Vec<&str> = steps.iter().map(|step| step.name.as_str()).collect();
+
+            assert_eq!(result.unwrap(), at(2024, 11, 20, 12, 0, 0));
+            assert_eq!(
+                names,
+                [
+                    "reference_time",
+                    "shift_to_weekday",
+                    "relative_date",
+                    "apply_offsets",
+                    "anchored_date"
+                ]
+            );
+            assert_eq!(steps[0].datetime, at(2024, 11, 10, 12, 0, 0));
+            assert_eq!(steps[1].detail, "next Mon from Sun: +8 days");
+            assert_eq!(steps[2].detail, "\"next Monday\"");
+            assert_eq!(steps[2].datetime, at(2024, 11, 18, 12, 0, 0));
+            assert_eq!(steps[3].detail, "+2 day_s");
+        }
+
+        #[test]
+        fn test_fuzzy_date_resolved_once() {
+            let config = pinned();
+            let (result, steps) = trace(|| parse_with_config("around 3pm", &config));
+            let names: Vec<&str> = steps.iter().map(|step| step.name.as_str()).collect();
+
+            assert!(result.unwrap().uncertainty.is_some());
+            assert_eq!(
+                names,
+                [
+                    "reference_time",
+                    "process_time_of_day",
+                    "time_expression",
+                    "fuzzy_date"
+                ]
+            );
+        }
+
+        #[test]
+        fn test_time_of_day_step() {
+            let config = pinned();
+            let (_, steps) = trace(|| from_string_with_config("tomorrow at 5 PM", &config));
+            let step = steps
+                .iter()
+                .find(|step| step.name == "process_time_of_day")
+                .unwrap();
+
+            assert_eq!(step.detail, "2024-11-11 17:00:00");
+            assert_eq!(step.datetime, at(2024, 11, 11, 17, 0, 0));
+        }
+
+        #[test]
+        fn test_nothing_recorded_outside_trace() {
+            let config = pinned();
+            from_string_with_config("next Monday", &config).unwrap();
+
+            let (_, steps) = trace(|| ());
+            assert!(steps.is_empty());
+        }
+
+        #[test]
+        fn test_nested_traces() {
+            let config = pinned();
+            let ((_, inner), outer) = trace(|| {
+                from_string_with_config("today", &config).unwrap();
+                trace(|| from_string_with_config("tomorrow", &config))
+            });
+
+            assert_eq!(inner.last().unwrap().detail, "\"tomorrow\"");
+            assert_eq!(outer.last().unwrap().detail, "\"today\"");
+        }
+    }
 }
@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use chrono::{Datelike, Duration, Local, TimeZone, Timelike, Weekday};
+    use chrono::{Datelike, Duration, Local, TimeZone, Timelike, Utc, Weekday};
+    use chrono_tz::America::New_York;
+    use chrono_tz::Australia::Lord_Howe;
     use natural_date_parser::date_parser;
+    use natural_date_parser::zones::{
+        AmbiguousTime, DstPolicy, DstResolution, ReferenceZone, SkippedTime,
+    };
     use natural_date_parser::{ParseDateError, Rule};
 
     #[test]
@@ -47,7 +52,9 @@ mod tests {
     #[test]
     fn test_adjust_to_next_weekday() {
         // Monday
-        let datetime = Local.with_ymd_and_hms(2024, 11, 11, 12, 0, 0).unwrap();
+        let datetime = ReferenceZone::Local
+            .with_ymd_and_hms(2024, 11, 11, 12, 0, 0)
+            .unwrap();
 
         let adjusted_date = date_parser::shift_to_weekday(datetime, Weekday::Fri, Rule::next);
         assert!(adjusted_date.is_ok());
@@ -62,7 +69,7 @@ mod tests {
 
     #[test]
     fn test_adjust_to_last_weekday() {
-        let now = Local::now();
+        let now = ReferenceZone::Local.now();
         let weekday = now.weekday();
 
         let adjusted_date = date_parser::shift_to_weekday(now, weekday, Rule::last);
@@ -73,11 +80,90 @@ mod tests {
 
     #[test]
     fn test_adjust_to_this_weekday() {
-        let now = Local::now();
+        let now = ReferenceZone::Local.now();
         let weekday = now.weekday();
 
         let adjusted_date = date_parser::shift_to_weekday(now, weekday, Rule::this);
         assert!(adjusted_date.is_ok());
         assert_eq!(adjusted_date.unwrap(), now);
     }
+
+    #[test]
+    fn test_change_time_ambiguous_policy() {
+        // Clocks in New York go back from 2:00 to 1:00 on November 3, 2024.
+        let day = New_York.with_ymd_and_hms(2024, 11, 3, 12, 0, 0).unwrap();
+        let policy = |ambiguous| DstPolicy {
+            ambiguous,
+            ..Default::default()
+        };
+
+        let (earliest, resolution) =
+            date_parser::change_time_with_policy(day, 1, 30, policy(AmbiguousTime::Earliest))
+                .unwrap();
+        assert_eq!(
+            earliest,
+            Utc.with_ymd_and_hms(2024, 11, 3, 5, 30, 0).unwrap()
+        );
+        assert_eq!(resolution, Some(DstResolution::Earliest));
+
+        let (latest, resolution) =
+            date_parser::change_time_with_policy(day, 1, 30, policy(AmbiguousTime::Latest))
+                .unwrap();
+        assert_eq!(latest, Utc.with_ymd_and_hms(2024, 11, 3, 6, 30, 0).unwrap());
+        assert_eq!(resolution, Some(DstResolution::Latest));
+
+        assert!(
+            date_parser::change_time_with_policy(day, 1, 30, policy(AmbiguousTime::Error)).is_err()
+        );
+        assert_eq!(date_parser::change_time(day, 1, 30).unwrap(), earliest);
+    }
+
+    #[test]
+    fn test_change_time_skipped_policy() {
+        // Clocks in New York go forward from 2:00 to 3:00 on March 10, 2024.
+        let day = New_York.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+        let policy = |skipped| DstPolicy {
+            skipped,
+            ..Default::default()
+        };
+
+        let (forward, resolution) =
+            date_parser::change_time_with_policy(day, 2, 30, policy(SkippedTime::ShiftForward))
+                .unwrap();
+        assert_eq!(forward.hour(), 3);
+        assert_eq!(forward.minute(), 30);
+        assert_eq!(resolution, Some(DstResolution::ShiftedForward));
+
+        let (back, resolution) =
+            date_parser::change_time_with_policy(day, 2, 30, policy(SkippedTime::ShiftBack))
+                .unwrap();
+        assert_eq!(back.hour(), 1);
+        assert_eq!(back.minute(), 30);
+        assert_eq!(resolution, Some(DstResolution::ShiftedBack));
+
+        assert!(
+            date_parser::change_time_with_policy(day, 2, 30, policy(SkippedTime::Error)).is_err()
+        );
+        assert_eq!(date_parser::change_time(day, 2, 30).unwrap(), forward);
+    }
+
+    #[test]
+    fn test_change_time_outside_transitions() {
+        let day = New_York.with_ymd_and_hms(2024, 3, 10, 12, 0, 0).unwrap();
+
+        let (datetime, resolution) =
+            date_parser::change_time_with_policy(day, 4, 0, DstPolicy::default()).unwrap();
+        assert_eq!(
+            datetime,
+            Utc.with_ymd_and_hms(2024, 3, 10, 8, 0, 0).unwrap()
+        );
+        assert_eq!(resolution, None);
+
+        // Lord Howe Island moves its clocks by half an hour.
+        let day = Lord_Howe.with_ymd_and_hms(2024, 10, 6, 12, 0, 0).unwrap();
+        let (datetime, resolution) =
+            date_parser::change_time_with_policy(day, 2, 15, DstPolicy::default()).unwrap();
+        assert_eq!((datetime.hour(), datetime.minute()), (2, 45));
+        assert_eq!(resolution, Some(DstResolution::ShiftedForward));
+    }
 }
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{FixedOffset, NaiveDate, TimeZone, Utc};
+    use chrono_tz::Tz;
+    use natural_date_parser::zones::{Zone, ZoneAbbreviations, parse_utc_offset, zone_for_city};
+
+    fn hours(hours: i32, minutes: i32) -> FixedOffset {
+        FixedOffset::east_opt(hours * 3600 + hours.signum() * minutes * 60).unwrap()
+    }
+
+    #[test]
+    fn test_parse_utc_offset() {
+        assert_eq!(parse_utc_offset("UTC+2"), Some(hours(2, 0)));
+        assert_eq!(parse_utc_offset("gmt-05:30"), Some(hours(-5, 30)));
+        assert_eq!(parse_utc_offset("UTC+0545"), Some(hours(5, 45)));
+        assert_eq!(parse_utc_offset("+10"), Some(hours(10, 0)));
+        assert_eq!(parse_utc_offset("UTC"), None);
+        assert_eq!(parse_utc_offset("UTC+15"), None);
+        assert_eq!(parse_utc_offset("UTC+02:75"), None);
+    }
+
+    #[test]
+    fn test_zone_for_city() {
+        assert_eq!(zone_for_city("Tokyo"), Some(Tz::Asia__Tokyo));
+        assert_eq!(zone_for_city("new york"), Some(Tz::America__New_York));
+        assert_eq!(zone_for_city("Kyiv"), Some(Tz::Europe__Kyiv));
+        assert_eq!(zone_for_city("Atlantis"), None);
+    }
+
+    #[test]
+    fn test_abbreviations() {
+        let mut abbreviations = ZoneAbbreviations::default();
+
+        assert_eq!(
+            abbreviations.get("est"),
+            Some(&[Zone::Fixed(hours(-5, 0))][..])
+        );
+        assert_eq!(
+            abbreviations.get("PT"),
+            Some(&[Zone::Named(Tz::America__Los_Angeles)][..])
+        );
+        assert_eq!(
+            abbreviations.get("CST"),
+            Some(&[Zone::Fixed(hours(-6, 0)), Zone::Fixed(hours(8, 0))][..])
+        );
+        assert_eq!(abbreviations.get("XYZ"), None);
+
+        abbreviations.prefer("CST", Zone::Fixed(hours(8, 0)));
+        assert_eq!(
+            abbreviations.get("CST"),
+            Some(&[Zone::Fixed(hours(8, 0)), Zone::Fixed(hours(-6, 0))][..])
+        );
+
+        let mut empty = ZoneAbbreviations::new();
+        assert_eq!(empty.get("UTC"), None);
+        empty.add("UTC", Zone::Fixed(hours(0, 0)));
+        empty.add("UTC", Zone::Fixed(hours(0, 0)));
+        assert_eq!(empty.get("utc").map(<[Zone]>::len), Some(1));
+    }
+
+    #[test]
+    fn test_zone() {
+        let naive = NaiveDate::from_ymd_opt(2024, 7, 1)
+            .unwrap()
+            .and_hms_opt(15, 0, 0)
+            .unwrap();
+        let new_york = Zone::Named(Tz::America__New_York);
+
+        assert_eq!(
+            new_york.from_local_datetime(&naive).unwrap(),
+            hours(-4, 0).from_local_datetime(&naive).unwrap()
+        );
+        assert_eq!(
+            new_york
+                .convert(&Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap())
+                .offset(),
+            &hours(-5, 0)
+        );
+
+        assert_eq!(new_york.to_string(), "America/New_York");
+        assert_eq!(Zone::Fixed(hours(0, 0)).to_string(), "UTC");
+        assert_eq!(Zone::Fixed(hours(5, 30)).to_string(), "UTC+05:30");
+        assert_eq!(Zone::Fixed(hours(-3, 30)).to_string(), "UTC-03:30");
+    }
+}